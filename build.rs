@@ -0,0 +1,36 @@
+//! Renders the shipped module templates, so that the tests of `cargo scaffold` build and run the
+//! modules a new day starts with (see `src/template/commands/scaffold.rs`).
+use std::{env, fs, io, path::Path};
+
+#[path = "src/template/commands/scaffold/render.rs"]
+mod render;
+
+const TEMPLATES: [&str; 3] = ["default", "nom-grid", "nom-lines"];
+/// Day 0 is no puzzle, its example `src/examples/00.txt` is empty like the one of a new day.
+const DAY: u8 = 0;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=templates");
+    println!("cargo:rerun-if-changed=src/template/commands/scaffold/render.rs");
+
+    let out_dir = Path::new(&env::var_os("OUT_DIR").expect("set by cargo")).join("templates");
+    fs::create_dir_all(&out_dir).expect("failed to create the output directory");
+
+    for name in TEMPLATES {
+        let path = format!("templates/{name}.rs");
+        let template = match fs::read_to_string(&path) {
+            Ok(template) => template,
+            // only the tests of the shipped templates need it
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => panic!("{path}: {e}"),
+        };
+        let module = render::render(&template, &render::placeholders(DAY, 2023, "u64"))
+            .unwrap_or_else(|e| panic!("{path}: {e}"));
+        fs::write(
+            out_dir.join(format!("{}.rs", name.replace('-', "_"))),
+            module,
+        )
+        .expect("failed to write a rendered template");
+    }
+}
@@ -0,0 +1,75 @@
+use crate::template::solution::Day;
+
+pub const YEAR: u16 = 2023;
+
+// Solutions are kept as they were written during the event, lints they trip are allowed per day.
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+#[allow(clippy::needless_return, clippy::get_first)]
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+#[allow(clippy::question_mark)]
+pub mod day17;
+pub mod day18;
+#[allow(clippy::unnecessary_get_then_check)]
+pub mod day19;
+#[allow(clippy::for_kv_map, clippy::iter_nth, clippy::bool_assert_comparison)]
+pub mod day20;
+#[allow(
+    clippy::new_without_default,
+    clippy::single_match,
+    clippy::clone_on_copy,
+    clippy::manual_is_multiple_of,
+    clippy::assign_op_pattern,
+    clippy::needless_arbitrary_self_type
+)]
+pub mod day21;
+#[allow(dead_code)]
+pub mod day22;
+#[allow(dead_code, unused_imports, clippy::useless_vec)]
+pub mod day23;
+#[allow(clippy::type_complexity)]
+pub mod day24;
+#[allow(clippy::trim_split_whitespace)]
+pub mod day25;
+
+/// All days, in order. `cargo scaffold` adds new days here.
+pub const ALL: &[Day] = &[
+    Day::of::<day01::Solver>(),
+    Day::of::<day02::Solver>(),
+    Day::of::<day03::Solver>(),
+    Day::of::<day04::Solver>(),
+    Day::of::<day05::Solver>(),
+    Day::of::<day06::Solver>(),
+    Day::of::<day07::Solver>(),
+    Day::of::<day08::Solver>(),
+    Day::of::<day09::Solver>(),
+    Day::of::<day10::Solver>(),
+    Day::of::<day11::Solver>(),
+    Day::of::<day12::Solver>(),
+    Day::of::<day13::Solver>(),
+    Day::of::<day14::Solver>(),
+    Day::of::<day15::Solver>(),
+    Day::of::<day16::Solver>(),
+    Day::of::<day17::Solver>(),
+    Day::of::<day18::Solver>(),
+    Day::of::<day19::Solver>(),
+    Day::of::<day20::Solver>(),
+    Day::of::<day21::Solver>(),
+    Day::of::<day22::Solver>(),
+    Day::of::<day23::Solver>(),
+    Day::of::<day24::Solver>(),
+    Day::of::<day25::Solver>(),
+];
@@ -1,144 +1,184 @@
 #![feature(step_trait)]
 #![feature(iter_order_by)]
+#![feature(iter_map_windows)]
+#![feature(thread_local)]
 /*
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
+use std::fmt::Display;
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 
 pub mod algebra_helpers;
+pub mod days;
+#[allow(clippy::ptr_arg)]
 pub mod helpers;
+pub mod template;
+pub mod years;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Runs a part and prints its result, see [`template::runner::run_part`].
+///
+/// Usage: `solve!(year, day, part, solver, input)` or
+/// `solve!(year, day, part, solver, input, &options)`, where `options` is a `RunOptions` selecting
+/// JSON records, benchmark mode and answer checks.
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
+    ($year:expr, $day:expr, $part:expr, $solver:expr, $input:expr) => {
+        $crate::solve!(
+            $year,
+            $day,
+            $part,
+            $solver,
+            $input,
+            &$crate::template::runner::RunOptions::default()
+        )
+    };
+    ($year:expr, $day:expr, $part:expr, $solver:expr, $input:expr, $options:expr) => {
+        $crate::template::runner::run_part($year, $day, $part, $solver, $input, $options)
+    };
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+/// Directory of a data folder (`inputs`, `examples`, `puzzles`, ...) for a year, relative to the
+/// crate root. The default year keeps the flat `src/<folder>` layout, other years live in
+/// `src/<folder>/<year>`.
+pub fn data_dir(folder: &str, year: u16) -> PathBuf {
+    let dir = PathBuf::from("src").join(folder);
+    if year == years::DEFAULT_YEAR {
+        dir
+    } else {
+        dir.join(year.to_string())
+    }
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Data files are resolved against the crate root instead of the working directory, so the
+/// binaries can be started from anywhere.
+pub fn get_file_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(data_dir(folder, year))
+        .join(format!("{day:02}.txt"))
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
+pub fn get_file_path_alternate(folder: &str, year: u16, day: u8, version: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(data_dir(folder, year))
+        .join(format!("{day:02}_{version:02}.txt"))
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// An input that could not be read, together with the path that was tried.
+#[derive(Debug)]
+pub struct InputError {
+    pub path: PathBuf,
+    pub source: io::Error,
 }
 
-pub fn read_file_alternate(folder: &str, day: u8, version: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+impl InputError {
+    pub fn is_not_found(&self) -> bool {
+        self.source.kind() == io::ErrorKind::NotFound
+    }
+}
 
-    let filepath = cwd
-        .join("src")
-        .join(folder)
-        .join(format!("{day:02}_{version:02}.txt"));
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "could not read \"{}\": {}",
+            self.path.display(),
+            self.source
+        )
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+fn read_path(path: PathBuf) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|source| InputError { path, source })
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
+/// Reads a data file, falling back to its [encrypted copy](template::store) if the file is
+/// missing.
+fn read_data_file(folder: &str, year: u16, path: PathBuf) -> Result<String, InputError> {
+    match read_path(path) {
+        Err(e) if e.is_not_found() => {
+            let file_name = e.path.file_name().unwrap_or_default().to_string_lossy();
+            template::store::read(folder, year, &file_name).unwrap_or(Err(e))
         }
-    })
+        result => result,
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Reads a data file, [normalized](template::input::normalize).
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> Result<String, InputError> {
+    try_read_file_raw(folder, year, day).map(|input| template::input::normalize(&input))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
+/// Reads a data file as it is on disk, or as it was encrypted.
+pub fn try_read_file_raw(folder: &str, year: u16, day: u8) -> Result<String, InputError> {
+    read_data_file(folder, year, get_file_path(folder, year, day))
+}
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
+pub fn try_read_file_alternate(
+    folder: &str,
+    year: u16,
+    day: u8,
+    version: u8,
+) -> Result<String, InputError> {
+    try_read_file_alternate_raw(folder, year, day, version)
+        .map(|input| template::input::normalize(&input))
+}
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
+pub fn try_read_file_alternate_raw(
+    folder: &str,
+    year: u16,
+    day: u8,
+    version: u8,
+) -> Result<String, InputError> {
+    read_data_file(
+        folder,
+        year,
+        get_file_path_alternate(folder, year, day, version),
+    )
+}
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+/// Reads an input given on the command line: a path (relative to the working directory) or `-`
+/// for stdin. The input is not normalized.
+pub fn read_input(source: &str) -> Result<String, InputError> {
+    if source == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|source| InputError {
+                path: PathBuf::from("<stdin>"),
+                source,
+            })?;
+        Ok(input)
+    } else {
+        read_path(PathBuf::from(source))
     }
 }
 
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    try_read_file(folder, year, day).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn read_file_alternate(folder: &str, year: u16, day: u8, version: u8) -> String {
+    try_read_file_alternate(folder, year, day, version).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn read_file_raw(folder: &str, year: u16, day: u8) -> String {
+    try_read_file_raw(folder, year, day).unwrap_or_else(|e| panic!("{e}"))
+}
+
 pub mod aoc_cli {
     use std::{
         fmt::Display,
@@ -146,6 +186,8 @@ pub mod aoc_cli {
         process::{Command, Output, Stdio},
     };
 
+    use crate::years::DEFAULT_YEAR;
+
     pub enum AocCliError {
         CommandNotFound,
         CommandNotCallable,
@@ -166,8 +208,16 @@ pub mod aoc_cli {
         }
     }
 
+    /// Overrides the aoc-cli command, e.g. when it is not on the `PATH`.
+    pub const COMMAND_ENV: &str = "AOC_CLI";
+
+    /// The aoc-cli command: [`COMMAND_ENV`] if set, `aoc` otherwise.
+    pub fn command() -> String {
+        std::env::var(COMMAND_ENV).unwrap_or_else(|_| "aoc".into())
+    }
+
     pub fn check() -> Result<(), AocCliError> {
-        Command::new("aoc")
+        Command::new(command())
             .arg("-V")
             .output()
             .map_err(|_| AocCliError::CommandNotFound)?;
@@ -175,16 +225,18 @@ pub mod aoc_cli {
     }
 
     pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
         let args = build_args("read", &[], day, year);
-        call_aoc_cli(&args)
+        call_aoc_cli(&command(), &args)
     }
 
     pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
+        let input_path = get_input_path(year.unwrap_or(DEFAULT_YEAR), day);
 
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
+        let puzzle_path = get_puzzle_path(year.unwrap_or(DEFAULT_YEAR), day);
+        for folder in ["inputs", "puzzles"] {
+            create_dir_all(crate::data_dir(folder, year.unwrap_or(DEFAULT_YEAR)))
+                .map_err(|_| AocCliError::IoError)?;
+        }
 
         let args = build_args(
             "download",
@@ -199,26 +251,43 @@ pub mod aoc_cli {
             year,
         );
 
-        let output = call_aoc_cli(&args)?;
+        let output = call_aoc_cli(&command(), &args)?;
 
         if output.status.success() {
             println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+            println!("🎄 Successfully wrote input to \"{}\".", input_path);
+            println!("🎄 Successfully wrote puzzle to \"{}\".", puzzle_path);
             Ok(output)
         } else {
             Err(AocCliError::BadExitStatus(output))
         }
     }
 
-    fn get_input_path(day: u8) -> String {
+    /// Submits an answer with the aoc-cli `command`. Output is captured so the response can be
+    /// classified by the caller.
+    pub fn submit(
+        command: &str,
+        day: u8,
+        year: Option<u16>,
+        part: u8,
+        answer: &str,
+    ) -> Result<Output, AocCliError> {
+        let mut args = build_args("submit", &[], day, year);
+        args.push(part.to_string());
+        args.push(answer.to_string());
+        call_aoc_cli_captured(command, &args)
+    }
+
+    fn get_input_path(year: u16, day: u8) -> String {
         let day_padded = format!("{day:02}");
-        format!("src/inputs/{day_padded}.txt")
+        let dir = crate::data_dir("inputs", year);
+        format!("{}/{day_padded}.txt", dir.display())
     }
 
-    fn get_puzzle_path(day: u8) -> String {
+    pub fn get_puzzle_path(year: u16, day: u8) -> String {
         let day_padded = format!("{day:02}");
-        format!("src/puzzles/{day_padded}.md")
+        let dir = crate::data_dir("puzzles", year);
+        format!("{}/{day_padded}.md", dir.display())
     }
 
     fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
@@ -234,16 +303,51 @@ pub mod aoc_cli {
         cmd_args
     }
 
-    fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
+    fn call_aoc_cli(command: &str, args: &[String]) -> Result<Output, AocCliError> {
         if cfg!(debug_assertions) {
-            println!("Calling >aoc with: {}", args.join(" "));
+            println!("Calling >{command} with: {}", args.join(" "));
         }
 
-        Command::new("aoc")
+        Command::new(command)
             .args(args)
             .stdout(Stdio::inherit())
             .stderr(Stdio::inherit())
             .output()
             .map_err(|_| AocCliError::CommandNotCallable)
     }
+
+    fn call_aoc_cli_captured(command: &str, args: &[String]) -> Result<Output, AocCliError> {
+        if cfg!(debug_assertions) {
+            println!("Calling >{command} with: {}", args.join(" "));
+        }
+
+        Command::new(command)
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|_| AocCliError::CommandNotCallable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_file_resolves_from_crate_root() {
+        let input = try_read_file("examples", years::DEFAULT_YEAR, 1).unwrap();
+        assert!(!input.is_empty());
+    }
+
+    #[test]
+    fn test_missing_file_names_path() {
+        let err = try_read_file_alternate("examples", years::DEFAULT_YEAR, 1, 99).unwrap_err();
+        assert!(err.is_not_found());
+        assert!(err.path.is_absolute());
+        assert!(err.to_string().contains("01_99.txt"));
+
+        let err = read_input("does/not/exist.txt").unwrap_err();
+        assert!(err.to_string().contains("does/not/exist.txt"));
+    }
 }
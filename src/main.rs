@@ -2,44 +2,77 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use std::process;
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+use advent_of_code::template::commands::{
+    self, download, inputs, read, readme, scaffold, submit, watch, GlobalOptions,
+};
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+const USAGE: &str = "Usage: cargo aoc <command> [--year <year>] [--release] [--features <features>] [--format <human|json>]
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+Commands:
+  scaffold <day> [--template <name>] [--answer-type <type>] [--example <n>] [--update-tests]
+  download <day>
+  inputs <export|import|keygen> [--force]
+  read <day>
+  solve <day> [--input <path> | --inputs-dir <dir>] [--raw-input] [--check] [--bench] [--timeout [<day>[.<part>]=]<ms>]
+  all [--jobs <n>] [--check] [--bench] [--timeout [<day>[.<part>]=]<ms>]
+  bench [<day>] [--warmup <n>] [--samples <n>] [--budget <ms>] [--compare] [--label <name>]
+  check [<day>]
+  submit <day> <part> [--answer <answer>] [--history]
+  watch <day> [--interval <ms>] [-- <solve options>]
+  readme
 
-            println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
-            println!("----------");
+`cargo scaffold`, `cargo download`, `cargo inputs`, `cargo read`, `cargo solve`, `cargo all`,
+`cargo submit`, `cargo watch` and `cargo readme` are shortcuts for the commands of the same name.
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+aoc-cli is called as `aoc`, set AOC_CLI to use another command.
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+With a key in AOC_INPUT_KEY or the file AOC_INPUT_KEY_FILE (default `.aoc-key`), inputs and
+answers are also kept encrypted in `src/encrypted` and read from there when the plain file is missing.";
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+    let (command, options) = match args
+        .subcommand()
+        .and_then(|command| Ok((command, GlobalOptions::from_args(&mut args)?)))
+    {
+        Ok((Some(command), options)) => (command, options),
+        Ok((None, _)) => {
+            eprintln!("{USAGE}");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            eprintln!("{USAGE}");
+            process::exit(1);
+        }
+    };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let result = match command.as_str() {
+        "scaffold" => scaffold::handle(args, &options),
+        "download" => download::handle(args, &options),
+        "inputs" => inputs::handle(args, &options),
+        "read" => read::handle(args, &options),
+        "submit" => submit::handle(args, &options),
+        "watch" => watch::handle(args, &options),
+        "readme" => readme::handle(args, &options),
+        "solve" | "all" | "bench" | "check" => {
+            if options.needs_rebuild() {
+                commands::rebuild(&options);
             }
-        })
-        .sum();
+            commands::run(args, &options, &command)
+        }
+        _ => {
+            eprintln!("Unknown command \"{command}\".");
+            eprintln!("{USAGE}");
+            process::exit(1);
+        }
+    };
 
-    println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+    if let Err(e) = result {
+        eprintln!("Failed to process arguments of `{command}`: {e}");
+        eprintln!("{USAGE}");
+        process::exit(1);
+    }
 }
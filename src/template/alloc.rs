@@ -0,0 +1,142 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// Global allocator that counts the allocations of the current thread. Installed with the
+/// `alloc-stats` feature, e.g. `cargo solve 14 --features alloc-stats`.
+///
+/// Counters are thread-local so that parts running in parallel (`cargo all --jobs`) or on a
+/// timeout thread do not see each other's allocations.
+pub struct CountingAllocator;
+
+#[thread_local]
+static ALLOCATIONS: Cell<usize> = Cell::new(0);
+#[thread_local]
+static ALLOCATED: Cell<usize> = Cell::new(0);
+/// Live bytes allocated by this thread. Memory freed by another thread is not subtracted here.
+#[thread_local]
+static LIVE: Cell<isize> = Cell::new(0);
+#[thread_local]
+static PEAK: Cell<isize> = Cell::new(0);
+
+fn on_alloc(size: usize) {
+    ALLOCATIONS.set(ALLOCATIONS.get() + 1);
+    ALLOCATED.set(ALLOCATED.get() + size);
+    LIVE.set(LIVE.get() + size as isize);
+    PEAK.set(PEAK.get().max(LIVE.get()));
+}
+
+fn on_dealloc(size: usize) {
+    LIVE.set(LIVE.get() - size as isize);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        on_alloc(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        on_alloc(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        on_dealloc(layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        on_dealloc(layout.size());
+        on_alloc(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Allocations made while running a part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// Highest number of bytes that were live at once, on top of what was live before the part.
+    pub peak: usize,
+}
+
+/// Whether the counting allocator is installed.
+pub const fn is_enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Runs `func` and counts the allocations it makes on the current thread. Returns `None` for the
+/// stats when the counting allocator is not installed.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !is_enabled() {
+        return (func(), None);
+    }
+
+    let (allocations, allocated, live) = (ALLOCATIONS.get(), ALLOCATED.get(), LIVE.get());
+    PEAK.set(live);
+
+    let result = func();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.get() - allocations,
+        bytes: ALLOCATED.get() - allocated,
+        peak: (PEAK.get() - live).max(0) as usize,
+    };
+    (result, Some(stats))
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.50 KiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{bytes} B"),
+        _ => format!("{value:.2} {}", UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| vec![0_u8; 4096].len());
+        assert_eq!(len, 4096);
+
+        match stats {
+            Some(stats) => {
+                assert_eq!(stats.allocations, 1);
+                assert_eq!(stats.bytes, 4096);
+                assert_eq!(stats.peak, 4096);
+            }
+            None => assert!(!is_enabled()),
+        }
+    }
+}
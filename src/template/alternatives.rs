@@ -0,0 +1,274 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{
+    answer::Answer,
+    answers::{Answers, CheckStatus},
+    record::Record,
+};
+
+/// Extension of the sidecar files holding the expected answers of an input.
+const SIDECAR_EXTENSION: &str = "toml";
+
+/// An input from `--inputs-dir`, e.g. the input of a teammate, to make sure a solution is not
+/// overfit to our own input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alternative {
+    pub name: String,
+    pub path: PathBuf,
+    /// Answers of the sidecar file next to the input (`alice.txt` → `alice.toml`, in the format of
+    /// `src/answers`), if there is one.
+    pub expected: Option<Result<Answers, String>>,
+}
+
+impl Alternative {
+    /// Compares an answer against the sidecar, `None` without sidecar.
+    pub fn check(&self, part: u8, answer: Option<&Answer>) -> Option<CheckStatus> {
+        self.expected.as_ref().map(|expected| match expected {
+            Ok(answers) => answers.check(part, answer),
+            Err(e) => CheckStatus::Error(e.clone()),
+        })
+    }
+}
+
+/// Lists the inputs of a directory in name order. Sidecars, hidden files and subdirectories are
+/// skipped.
+pub fn discover(dir: &Path) -> Result<Vec<Alternative>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+
+    let mut alternatives = vec![];
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("{}: {e}", dir.display()))?;
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let is_sidecar = path
+            .extension()
+            .is_some_and(|extension| extension == SIDECAR_EXTENSION);
+        if name.starts_with('.') || is_sidecar || !path.is_file() {
+            continue;
+        }
+
+        let sidecar = path.with_extension(SIDECAR_EXTENSION);
+        let expected = match fs::read_to_string(&sidecar) {
+            Ok(contents) => {
+                Some(Answers::parse(&contents).map_err(|e| format!("{}: {e}", sidecar.display())))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => Some(Err(format!("{}: {e}", sidecar.display()))),
+        };
+        alternatives.push(Alternative {
+            name,
+            path,
+            expected,
+        });
+    }
+
+    alternatives.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(alternatives)
+}
+
+/// Results of a day for one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub name: String,
+    /// One record per phase, as returned by the runner. Empty if the input could not be read.
+    pub records: Vec<Record>,
+}
+
+fn format_record(record: &Record) -> String {
+    if let Some(failure) = &record.failure {
+        return failure.as_str().to_string();
+    }
+    let elapsed = format!("{:.2?}", record.elapsed);
+    if record.is_parse() {
+        return elapsed;
+    }
+    let Some(answer) = &record.answer else {
+        return "not solved".into();
+    };
+
+    let check = match &record.check {
+        Some(CheckStatus::Pass) => " ✅".to_string(),
+        Some(CheckStatus::Fail { expected }) => format!(" ❌ expected {expected}"),
+        Some(CheckStatus::Error(_)) => " ❌ unreadable sidecar".into(),
+        Some(CheckStatus::Missing) | None => String::new(),
+    };
+    format!("{answer}{check} · {elapsed}")
+}
+
+/// Terminal columns taken by a cell, the check marks are double width.
+fn display_width(cell: &str) -> usize {
+    cell.chars()
+        .map(|c| if matches!(c, '✅' | '❌') { 2 } else { 1 })
+        .sum()
+}
+
+/// Renders the results of every input as an aligned table, with a parse column for two-phase
+/// days.
+pub fn table(rows: &[Row]) -> String {
+    let two_phase = rows
+        .iter()
+        .flat_map(|row| &row.records)
+        .any(Record::is_parse);
+    let parts: &[u8] = if two_phase { &[0, 1, 2] } else { &[1, 2] };
+
+    let mut lines: Vec<Vec<String>> = vec![std::iter::once("Input".to_string())
+        .chain(parts.iter().map(|&part| match part {
+            0 => "Parse".to_string(),
+            part => format!("Part {part}"),
+        }))
+        .collect()];
+    for row in rows {
+        let mut line = vec![row.name.clone()];
+        line.extend(parts.iter().map(|&part| {
+            match row.records.iter().find(|record| record.part == part) {
+                Some(record) => format_record(record),
+                None => "-".into(),
+            }
+        }));
+        lines.push(line);
+    }
+
+    let widths: Vec<usize> = (0..=parts.len())
+        .map(|column| {
+            lines
+                .iter()
+                .map(|line| display_width(&line[column]))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let render = |line: &[String]| {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell}{}", " ".repeat(width - display_width(cell))))
+            .collect();
+        format!("{}\n", cells.join(" | ").trim_end())
+    };
+    let separator = widths
+        .iter()
+        .map(|&width| "-".repeat(width))
+        .collect::<Vec<_>>()
+        .join("-+-");
+
+    let mut table = render(&lines[0]);
+    table += &format!("{separator}\n");
+    for line in &lines[1..] {
+        table += &render(line);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::template::record::Failure;
+
+    fn record(part: u8, answer: Option<u64>, check: Option<CheckStatus>) -> Record {
+        Record {
+            year: 2023,
+            day: 20,
+            part,
+            answer: answer.map(Answer::from),
+            elapsed: Duration::from_micros(1500),
+            bench: None,
+            alloc: None,
+            check,
+            failure: None,
+            unreliable: false,
+        }
+    }
+
+    #[test]
+    fn test_discover() {
+        let dir = std::env::temp_dir().join(format!("aoc-alternatives-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("bob.txt"), "2\n").unwrap();
+        fs::write(dir.join("alice.txt"), "1\n").unwrap();
+        fs::write(dir.join("alice.toml"), "part1 = 7\n").unwrap();
+        fs::write(dir.join("carol"), "3\n").unwrap();
+        fs::write(dir.join("carol.toml"), "part3 = 7\n").unwrap();
+        fs::write(dir.join(".hidden"), "").unwrap();
+
+        let alternatives = discover(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let names: Vec<_> = alternatives.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["alice.txt", "bob.txt", "carol"]);
+
+        let [alice, bob, carol] = &alternatives[..] else {
+            unreachable!()
+        };
+        assert_eq!(alice.path, dir.join("alice.txt"));
+        assert_eq!(
+            alice.check(1, Some(&Answer::from(7))),
+            Some(CheckStatus::Pass)
+        );
+        assert_eq!(alice.check(2, None), Some(CheckStatus::Missing));
+        assert_eq!(bob.check(1, Some(&Answer::from(7))), None);
+        assert!(carol.check(1, None).is_some_and(|c| c.is_failure()));
+
+        assert!(discover(&dir).is_err());
+    }
+
+    #[test]
+    fn test_table() {
+        let rows = [
+            Row {
+                name: "alice.txt".into(),
+                records: vec![
+                    record(1, Some(7), Some(CheckStatus::Pass)),
+                    record(
+                        2,
+                        Some(8),
+                        Some(CheckStatus::Fail {
+                            expected: Answer::from(9),
+                        }),
+                    ),
+                ],
+            },
+            Row {
+                name: "bob.txt".into(),
+                records: vec![
+                    Record {
+                        failure: Some(Failure::Panicked("no node zh".into())),
+                        ..record(1, None, None)
+                    },
+                    record(2, None, None),
+                ],
+            },
+            Row {
+                name: "missing.txt".into(),
+                records: vec![],
+            },
+        ];
+        assert_eq!(
+            table(&rows),
+            "\
+Input       | Part 1        | Part 2
+------------+---------------+-------------------------
+alice.txt   | 7 ✅ · 1.50ms | 8 ❌ expected 9 · 1.50ms
+bob.txt     | panic         | not solved
+missing.txt | -             | -
+"
+        );
+
+        let rows = [Row {
+            name: "a".into(),
+            records: vec![record(0, None, None), record(1, Some(1), None)],
+        }];
+        assert_eq!(
+            table(&rows).lines().next(),
+            Some("Input | Parse  | Part 1     | Part 2")
+        );
+    }
+}
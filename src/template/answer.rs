@@ -0,0 +1,198 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+/// The answer to a part: an integer of any width or a string (e.g. letters read off an ASCII-art
+/// grid).
+///
+/// Integers compare by value regardless of their original type, and strings that hold an integer
+/// in its canonical form are integers, so `Answer::from(42_u8) == Answer::from("42")`. Other
+/// strings of digits such as `"007"` or `"+42"` stay text, as a puzzle asking for them would not
+/// accept `7` or `42`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Always negative, non-negative integers are stored as [`Answer::Unsigned`].
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+}
+
+impl Answer {
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Signed(n) => Some(*n),
+            Answer::Unsigned(n) => i128::try_from(*n).ok(),
+            Answer::Text(_) => None,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        !matches!(self, Answer::Text(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.to_string().into())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        if !is_canonical_integer(&s) {
+            Answer::Text(s)
+        } else if let Ok(n) = s.parse::<u128>() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = s.parse::<i128>() {
+            n.into()
+        } else {
+            Answer::Text(s)
+        }
+    }
+}
+
+/// Whether `s` is an integer as it is displayed: no sign but a `-` and no leading zeros.
+fn is_canonical_integer(s: &str) -> bool {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    match digits.as_bytes() {
+        [b'0'] => digits.len() == s.len(),
+        [b'1'..=b'9', rest @ ..] => rest.iter().all(u8::is_ascii_digit),
+        _ => false,
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        s.to_string().into()
+    }
+}
+
+impl From<&String> for Answer {
+    fn from(s: &String) -> Self {
+        s.as_str().into()
+    }
+}
+
+macro_rules! unsigned_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Unsigned(n as u128)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! signed_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match u128::try_from(n) {
+                        Ok(n) => Answer::Unsigned(n),
+                        Err(_) => Answer::Signed(n as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+unsigned_answer!(u8, u16, u32, u64, u128, usize);
+signed_answer!(i8, i16, i32, i64, i128, isize);
+
+/// Expected answer in [`example_tests!`](crate::example_tests). Only implemented for one integer
+/// type, so that plain integer literals of any size are inferred as `i128` instead of overflowing
+/// the default `i32`.
+pub trait Expected {
+    fn into_answer(self) -> Answer;
+}
+
+impl Expected for i128 {
+    fn into_answer(self) -> Answer {
+        self.into()
+    }
+}
+
+impl Expected for &str {
+    fn into_answer(self) -> Answer {
+        self.into()
+    }
+}
+
+impl Expected for String {
+    fn into_answer(self) -> Answer {
+        self.into()
+    }
+}
+
+impl Expected for Answer {
+    fn into_answer(self) -> Answer {
+        self
+    }
+}
+
+/// Converts the expected value of an example test, see [`Expected`].
+pub fn expected<E: Expected>(expected: Option<E>) -> Option<Answer> {
+    expected.map(Expected::into_answer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers_compare_by_value() {
+        assert_eq!(Answer::from(42_u8), Answer::from(42_i64));
+        assert_eq!(Answer::from(42_usize), Answer::from("42"));
+        assert_eq!(Answer::from(-7_isize), Answer::from("-7"));
+        assert_ne!(Answer::from(-7_i32), Answer::from(7_u32));
+        assert_eq!(Answer::from(u128::MAX), Answer::from(u128::MAX.to_string()));
+        assert_eq!(Answer::from(i128::MIN).as_i128(), Some(i128::MIN));
+    }
+
+    #[test]
+    fn test_expected_literals() {
+        assert_eq!(
+            expected(Some(808107741406756)),
+            Some(Answer::from(808107741406756_u64))
+        );
+        assert_eq!(expected(Some(-3)), Some(Answer::from(-3_i8)));
+        assert_eq!(expected(Some("ABC")), Some(Answer::from("ABC")));
+    }
+
+    #[test]
+    fn test_text() {
+        let answer = Answer::from("EFEAZBGK");
+        assert_eq!(answer, Answer::Text("EFEAZBGK".into()));
+        assert!(!answer.is_numeric());
+        assert_eq!(answer.to_string(), "EFEAZBGK");
+        assert_eq!(Answer::from(" 42"), Answer::Text(" 42".into()));
+    }
+
+    #[test]
+    fn test_non_canonical_integers_are_text() {
+        for s in ["007", "0042", "+42", "-0", "-007", "00", "-", ""] {
+            assert_eq!(Answer::from(s), Answer::Text(s.into()), "{s:?}");
+        }
+        assert_ne!(Answer::from("0042"), Answer::from(42_u64));
+        assert_eq!(Answer::from("0"), Answer::from(0_u8));
+        assert_eq!(Answer::from("-10"), Answer::from(-10_i8));
+    }
+}
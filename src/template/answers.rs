@@ -0,0 +1,378 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{answer::Answer, store};
+
+/// Recorded answers for the real input of a day, stored in `src/answers/NN.toml` (or
+/// `src/answers/<year>/NN.toml` for other years than the default):
+///
+/// ```toml
+/// part1 = 1234
+/// part2 = "abc"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl Answers {
+    /// Resolved against the crate root like the inputs, so answers are found from any directory.
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(crate::data_dir("answers", year))
+            .join(format!("{day:02}.toml"))
+    }
+
+    /// Returns `Ok(None)` if no answers file exists for the day, neither as plain file nor as a
+    /// [decryptable copy](super::store).
+    pub fn load(year: u16, day: u8) -> Result<Option<Self>, String> {
+        let path = Self::path(year, day);
+        let (path, contents) = match fs::read_to_string(&path) {
+            Ok(contents) => (path, contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                match store::read("answers", year, &format!("{day:02}.toml")) {
+                    None => return Ok(None),
+                    Some(Err(e)) if e.is_not_found() => return Ok(None),
+                    Some(Err(e)) => return Err(e.to_string()),
+                    Some(Ok(contents)) => (
+                        store::path("answers", year, &format!("{day:02}.toml")),
+                        contents,
+                    ),
+                }
+            }
+            Err(e) => return Err(format!("{}: {e}", path.display())),
+        };
+        Self::parse(&contents)
+            .map(Some)
+            .map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+            let value = parse_value(value.trim())
+                .ok_or_else(|| format!("line {}: invalid value `{}`", i + 1, value.trim()))?;
+
+            match key.trim() {
+                "part1" => answers.part_one = Some(value),
+                "part2" => answers.part_two = Some(value),
+                key => return Err(format!("line {}: unknown key `{key}`", i + 1)),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    /// Stores an answer for a part, keeping the other part untouched, and returns the path
+    /// written. With a [key](store::Key) only the encrypted copy is written (and a plain file left
+    /// from before is removed), so answers never end up in the repository in plain text.
+    pub fn record(year: u16, day: u8, part: u8, answer: &Answer) -> Result<PathBuf, String> {
+        let mut answers = Self::load(year, day)?.unwrap_or_default();
+        match part {
+            1 => answers.part_one = Some(answer.clone()),
+            2 => answers.part_two = Some(answer.clone()),
+            _ => return Err(format!("invalid part {part}")),
+        }
+
+        let path = Self::path(year, day);
+        let contents = answers.to_toml();
+
+        if let Some(key) = store::Key::load()? {
+            let file_name = format!("{day:02}.toml");
+            store::write(&key, "answers", year, &file_name, &contents)?;
+            match fs::remove_file(&path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => {
+                    return Err(format!("{}: {e}", path.display()))
+                }
+                _ => {}
+            }
+            return Ok(store::path("answers", year, &file_name));
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        fs::write(&path, &contents).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(path)
+    }
+
+    pub fn to_toml(&self) -> String {
+        [("part1", &self.part_one), ("part2", &self.part_two)]
+            .into_iter()
+            .filter_map(|(key, value)| {
+                value
+                    .as_ref()
+                    .map(|v| format!("{key} = \"{}\"\n", escape(&v.to_string())))
+            })
+            .collect()
+    }
+
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_one.as_ref(),
+            2 => self.part_two.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Compares an answer against the answer of its part.
+    pub fn check(&self, part: u8, answer: Option<&Answer>) -> CheckStatus {
+        match self.get(part) {
+            Some(expected) if Some(expected) == answer => CheckStatus::Pass,
+            Some(expected) => CheckStatus::Fail {
+                expected: expected.clone(),
+            },
+            None => CheckStatus::Missing,
+        }
+    }
+}
+
+/// Escapes an answer for a TOML basic string, e.g. an ASCII art answer spanning several lines.
+/// Also keeps answers on a single field of the submission history.
+pub(super) fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Reverses [`escape`]. `None` if `s` has an invalid escape sequence.
+pub(super) fn unescape(s: &str) -> Option<String> {
+    unescape_prefix(s, false).map(|(unescaped, _)| unescaped)
+}
+
+/// Reads a TOML basic string up to its closing quote, returning the string and the rest of the
+/// line.
+fn unescape_quoted(s: &str) -> Option<(String, &str)> {
+    unescape_prefix(s, true)
+}
+
+fn unescape_prefix(s: &str, quoted: bool) -> Option<(String, &str)> {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' if quoted => return Some((unescaped, &s[i + 1..])),
+            '\\' => {
+                let c = match chars.next()?.1 {
+                    'b' => '\u{8}',
+                    't' => '\t',
+                    'n' => '\n',
+                    'f' => '\u{c}',
+                    'r' => '\r',
+                    '"' => '"',
+                    '\\' => '\\',
+                    escape @ ('u' | 'U') => {
+                        let len = if escape == 'u' { 4 } else { 8 };
+                        let hex: String = chars.by_ref().take(len).map(|(_, c)| c).collect();
+                        if hex.len() != len {
+                            return None;
+                        }
+                        char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+                    }
+                    _ => return None,
+                };
+                unescaped.push(c);
+            }
+            c => unescaped.push(c),
+        }
+    }
+    (!quoted).then_some((unescaped, ""))
+}
+
+/// Accepts quoted strings and bare integers, ignoring trailing comments.
+fn parse_value(value: &str) -> Option<Answer> {
+    if let Some(rest) = value.strip_prefix('"') {
+        let (inner, trailing) = unescape_quoted(rest)?;
+        let trailing = trailing.trim();
+        if !trailing.is_empty() && !trailing.starts_with('#') {
+            return None;
+        }
+        return Some(inner.into());
+    }
+
+    let value = value.split('#').next()?.trim();
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(value.into())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Fail {
+        expected: Answer,
+    },
+    /// No answer recorded for this part.
+    Missing,
+    /// The answers file could not be read.
+    Error(String),
+}
+
+impl CheckStatus {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, CheckStatus::Fail { .. } | CheckStatus::Error(_))
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "pass",
+            CheckStatus::Fail { .. } => "fail",
+            CheckStatus::Missing => "missing",
+            CheckStatus::Error(_) => "error",
+        }
+    }
+}
+
+impl Display for CheckStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckStatus::Pass => write!(f, "✅ matches recorded answer"),
+            CheckStatus::Fail { expected } => write!(f, "❌ expected {expected}"),
+            CheckStatus::Missing => write!(f, "❔ no recorded answer"),
+            CheckStatus::Error(e) => write!(f, "❌ could not read answers: {e}"),
+        }
+    }
+}
+
+/// Compares an answer against the recorded answers of its day.
+pub fn check(year: u16, day: u8, part: u8, answer: Option<&Answer>) -> CheckStatus {
+    match Answers::load(year, day) {
+        Ok(Some(answers)) => answers.check(part, answer),
+        Ok(None) => CheckStatus::Missing,
+        Err(e) => CheckStatus::Error(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# day 1\npart1 = 54304\npart2 = \"54418\" # verified\n");
+        assert_eq!(
+            answers,
+            Ok(Answers {
+                part_one: Some("54304".into()),
+                part_two: Some("54418".into()),
+            })
+        );
+
+        let answers = Answers::parse("part1 = -12\n").unwrap();
+        assert_eq!(answers.get(1), Some(&Answer::from(-12)));
+        assert_eq!(answers.get(2), None);
+    }
+
+    #[test]
+    fn test_to_toml() {
+        let answers = Answers {
+            part_one: Some("209".into()),
+            part_two: None,
+        };
+        assert_eq!(answers.to_toml(), "part1 = \"209\"\n");
+        assert_eq!(Answers::parse(&answers.to_toml()), Ok(answers));
+
+        let answers = Answers {
+            part_one: Some("say \"hi\" \\o/".into()),
+            part_two: Some("#..#\n#..#\t\u{7}".into()),
+        };
+        assert_eq!(
+            answers.to_toml(),
+            "part1 = \"say \\\"hi\\\" \\\\o/\"\npart2 = \"#..#\\n#..#\\t\\u0007\"\n"
+        );
+        assert_eq!(Answers::parse(&answers.to_toml()), Ok(answers));
+    }
+
+    #[test]
+    fn test_parse_escapes() {
+        let answers = Answers::parse("part1 = \"a\\\"b\\u00e9\\U0001F384\" # \"c\"\n").unwrap();
+        assert_eq!(answers.get(1), Some(&Answer::from("a\"bé🎄")));
+
+        assert!(Answers::parse("part1 = \"abc\\\"").is_err());
+        assert!(Answers::parse("part1 = \"a\\xb\"").is_err());
+        assert!(Answers::parse("part1 = \"\\u12\"").is_err());
+        assert!(Answers::parse("part1 = \"\\uD800\"").is_err());
+    }
+
+    #[test]
+    fn test_compares_as_answer() {
+        let answers = Answers::parse("part1 = \"42\"\npart2 = \"EFEAZBGK\"\n").unwrap();
+        assert_eq!(answers.get(1), Some(&Answer::from(42_u64)));
+        assert_eq!(answers.get(2), Some(&Answer::from("EFEAZBGK")));
+        // leading zeros are part of the answer
+        let answers = Answers::parse("part1 = \"0042\"").unwrap();
+        assert_ne!(answers.get(1), Some(&Answer::from(42_u64)));
+        assert_eq!(answers.get(1), Some(&Answer::from("0042")));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("part1 54304").is_err());
+        assert!(Answers::parse("part3 = 1").is_err());
+        assert!(Answers::parse("part1 = 12ab").is_err());
+        assert!(Answers::parse("part1 = \"12\" x").is_err());
+    }
+
+    /// `cargo aoc` can be run from any directory, the answers are still those of the crate.
+    #[test]
+    fn test_check_from_other_cwd() {
+        // not a puzzle year, so no real answers are touched
+        const YEAR: u16 = 1;
+        const CHILD_ENV: &str = "AOC_TEST_OTHER_CWD";
+        if std::env::var_os(CHILD_ENV).is_some() {
+            assert_eq!(check(YEAR, 1, 1, Some(&Answer::from(7))), CheckStatus::Pass);
+            return;
+        }
+
+        let path = Answers::path(YEAR, 1);
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir).unwrap();
+        fs::write(&path, "part1 = 7\n").unwrap();
+        let cwd = std::env::temp_dir().join(format!("aoc-answers-cwd-{}", std::process::id()));
+        fs::create_dir_all(&cwd).unwrap();
+
+        // runs this test again in a child process started from `cwd`
+        let status = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "template::answers::tests::test_check_from_other_cwd",
+            ])
+            .env(CHILD_ENV, "1")
+            .current_dir(&cwd)
+            .output()
+            .unwrap()
+            .status;
+        fs::remove_dir_all(dir).unwrap();
+        fs::remove_dir_all(&cwd).unwrap();
+        assert!(status.success());
+    }
+}
@@ -0,0 +1,167 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// Settings for `--bench` mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Unmeasured runs before sampling starts.
+    pub warmup: usize,
+    /// Fixed number of samples. If unset, parts are sampled until `budget` is used up.
+    pub samples: Option<usize>,
+    /// Time budget per part, used when `samples` is unset.
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            samples: None,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Parses `--warmup <n>`, `--samples <n>` and `--budget <ms>`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let default = Self::default();
+        Ok(Self {
+            warmup: args
+                .opt_value_from_str("--warmup")?
+                .unwrap_or(default.warmup),
+            samples: args.opt_value_from_str("--samples")?,
+            budget: args
+                .opt_value_from_str("--budget")?
+                .map(Duration::from_millis)
+                .unwrap_or(default.budget),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    /// Returns `None` for an empty sample set.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean_ns = sorted.iter().map(|d| d.as_nanos() as f64).sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|d| (d.as_nanos() as f64 - mean_ns).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean_ns.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+/// Benchmarks a part. The answer is taken from the first (unmeasured) run; unsolved parts are
+/// not sampled.
+pub fn bench<I: ?Sized, T>(
+    func: impl Fn(&I) -> Option<T>,
+    input: &I,
+    config: &BenchConfig,
+) -> (Option<T>, Option<BenchStats>) {
+    let answer = func(input);
+    if answer.is_none() {
+        return (None, None);
+    }
+
+    for _ in 1..config.warmup {
+        black_box(func(black_box(input)));
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+    loop {
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        samples.push(timer.elapsed());
+
+        let done = match config.samples {
+            Some(n) => samples.len() >= n,
+            None => start.elapsed() >= config.budget,
+        };
+        if done {
+            break;
+        }
+    }
+
+    (answer, BenchStats::from_samples(&samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [4, 1, 3, 2, 5].map(Duration::from_micros);
+        let stats = BenchStats::from_samples(&samples).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        // sqrt(2.5) µs
+        assert_eq!(stats.stddev, Duration::from_nanos(1581));
+
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let stats = BenchStats::from_samples(&samples).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench_fixed_samples() {
+        let config = BenchConfig {
+            warmup: 0,
+            samples: Some(7),
+            budget: Duration::ZERO,
+        };
+        let (answer, stats) = bench(|input| Some(input.len()), "abc", &config);
+        assert_eq!(answer, Some(3));
+        assert_eq!(stats.unwrap().samples, 7);
+
+        let (answer, stats) = bench(|_| None::<u32>, "abc", &config);
+        assert_eq!(answer, None);
+        assert_eq!(stats, None);
+    }
+}
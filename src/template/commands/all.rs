@@ -0,0 +1,84 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    process, thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    template::{
+        answers::CheckStatus,
+        record::Failure,
+        runner::{self, RunOptions},
+        timings::{self, HistoryOptions},
+    },
+    years, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Runs every registered day of `year` on `jobs` workers (`0` uses one worker per available core)
+/// and prints the totals.
+pub fn handle(year: u16, options: &RunOptions, history: &HistoryOptions, jobs: usize) {
+    let Some(year) = years::get(year) else {
+        eprintln!("No solutions registered for year {year}.");
+        process::exit(1);
+    };
+
+    let jobs = match jobs {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        jobs => jobs,
+    };
+
+    let timer = Instant::now();
+    let records = runner::run_all(year.days, options, jobs);
+    let wall_clock = timer.elapsed();
+
+    let total: Duration = records
+        .iter()
+        .filter(|record| record.has_timing())
+        .map(|record| record.elapsed)
+        .sum();
+
+    if options.is_human() {
+        let total = total.as_secs_f64() * 1000_f64;
+        let wall_clock = wall_clock.as_secs_f64() * 1000_f64;
+        println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+        println!("{ANSI_BOLD}Wall clock:{ANSI_RESET} {ANSI_ITALIC}{wall_clock:.2}ms ({jobs} jobs){ANSI_RESET}");
+
+        let failed = |kind: fn(&Failure) -> bool| {
+            records
+                .iter()
+                .filter(|r| r.failure.as_ref().is_some_and(kind))
+                .count()
+        };
+        let timed_out = failed(|f| matches!(f, Failure::TimedOut(_)));
+        let panicked = failed(|f| matches!(f, Failure::Panicked(_)));
+        if timed_out + panicked > 0 {
+            println!("{ANSI_BOLD}Failed:{ANSI_RESET} {timed_out} timed out, {panicked} panicked");
+        }
+
+        if options.check {
+            let count = |status: fn(&CheckStatus) -> bool| {
+                records
+                    .iter()
+                    .filter(|r| r.check.as_ref().is_some_and(status))
+                    .count()
+            };
+            println!(
+                "{ANSI_BOLD}Check:{ANSI_RESET} {} passed, {} failed, {} missing",
+                count(|c| matches!(c, CheckStatus::Pass)),
+                count(|c| matches!(c, CheckStatus::Fail { .. } | CheckStatus::Error(_))),
+                count(|c| matches!(c, CheckStatus::Missing))
+            );
+        }
+    }
+
+    if let Err(e) = timings::record(year.year, &records, history, options.is_human()) {
+        eprintln!("Failed to update timing history: {e}");
+    }
+
+    if runner::has_check_failures(&records) {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,56 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pico_args::{Arguments, Error};
+use std::{fs, process};
+
+use super::GlobalOptions;
+use crate::{aoc_cli, template::store};
+
+/// `download <day>`: downloads the input and puzzle of a day with aoc-cli. With a configured
+/// [key](store::Key), the input is also written to the encrypted store.
+pub fn handle(mut args: Arguments, options: &GlobalOptions) -> Result<(), Error> {
+    let day: u8 = args.free_from_str()?;
+    super::finish(args)?;
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    match aoc_cli::download(day, Some(options.year)) {
+        Ok(cmd_output) => {
+            if !cmd_output.status.success() {
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("failed to spawn aoc-cli: {e}");
+            process::exit(1);
+        }
+    }
+
+    if let Err(e) = store_input(day, options.year) {
+        eprintln!("failed to store the encrypted input: {e}");
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// Keeps an encrypted copy of the downloaded input if a key is configured.
+fn store_input(day: u8, year: u16) -> Result<(), String> {
+    let Some(key) = store::Key::load()? else {
+        return Ok(());
+    };
+    let file_name = format!("{day:02}.txt");
+    let path = store::plain_path("inputs", year, &file_name);
+    let input = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    store::write(&key, "inputs", year, &file_name, &input)?;
+    println!(
+        "🔒 Successfully stored encrypted input in \"{}\".",
+        store::path("inputs", year, &file_name).display()
+    );
+    Ok(())
+}
@@ -0,0 +1,140 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pico_args::{Arguments, Error};
+use std::{fs, path::Path, process};
+
+use super::GlobalOptions;
+use crate::template::store::{self, Key};
+
+/// `inputs <export|import|keygen>`: moves the inputs and answers of a year between their plain
+/// data folders and the encrypted store, so they can be committed and restored on other machines.
+pub fn handle(mut args: Arguments, options: &GlobalOptions) -> Result<(), Error> {
+    let action: String = args.free_from_str()?;
+    let force = action == "import" && args.contains("--force");
+    super::finish(args)?;
+
+    let result = match action.as_str() {
+        "keygen" => keygen(),
+        "export" => export(options.year),
+        "import" => import(options.year, force),
+        _ => {
+            return Err(super::invalid(
+                &action,
+                "expected `export`, `import` or `keygen`",
+            ))
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn keygen() -> Result<(), String> {
+    let path = store::key_file();
+    if path.exists() {
+        return Err(format!(
+            "\"{}\" already exists, delete it first to replace the key.",
+            path.display()
+        ));
+    }
+    Key::write_new(&path)?;
+    println!("🔑 Wrote a new key to \"{}\".", path.display());
+    println!("Share it with your team through a private channel, never commit it.");
+    Ok(())
+}
+
+fn load_key() -> Result<Key, String> {
+    Key::load()?.ok_or_else(|| {
+        format!(
+            "No key configured. Set {} or {}, or create one with `cargo aoc inputs keygen`.",
+            store::KEY_ENV,
+            store::KEY_FILE_ENV
+        )
+    })
+}
+
+/// Names of the files in `dir`, skipping directories (other years) and hidden files.
+fn file_names(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| !name.starts_with('.'))
+        .collect();
+    names.sort();
+    names
+}
+
+fn export(year: u16) -> Result<(), String> {
+    let key = load_key()?;
+    let (mut written, mut unchanged) = (0, 0);
+
+    for folder in store::FOLDERS {
+        let dir = store::plain_path(folder, year, "");
+        for file_name in file_names(&dir) {
+            let contents = fs::read_to_string(dir.join(&file_name))
+                .map_err(|e| format!("{}: {e}", dir.join(&file_name).display()))?;
+            if store::write(&key, folder, year, &file_name, &contents)? {
+                println!("🔒 {folder}/{file_name}");
+                written += 1;
+            } else {
+                unchanged += 1;
+            }
+        }
+    }
+
+    println!(
+        "Exported {written} files of {year} to \"{}\" ({unchanged} unchanged).",
+        store::dir("", year).display()
+    );
+    Ok(())
+}
+
+fn import(year: u16, force: bool) -> Result<(), String> {
+    load_key()?;
+    let (mut written, mut unchanged, mut skipped) = (0, 0, 0);
+
+    for folder in store::FOLDERS {
+        for name in file_names(&store::dir(folder, year)) {
+            let Some(file_name) = name.strip_suffix(".enc") else {
+                continue;
+            };
+            let contents = store::read(folder, year, file_name)
+                .expect("stored copy was listed")
+                .map_err(|e| e.to_string())?;
+
+            let path = store::plain_path(folder, year, file_name);
+            match fs::read_to_string(&path) {
+                Ok(existing) if existing == contents => {
+                    unchanged += 1;
+                    continue;
+                }
+                Ok(_) if !force => {
+                    eprintln!("⚠️ {folder}/{file_name} differs from the stored copy, skipped.");
+                    skipped += 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            fs::create_dir_all(store::plain_path(folder, year, ""))
+                .and_then(|_| fs::write(&path, contents))
+                .map_err(|e| format!("{}: {e}", path.display()))?;
+            println!("🔓 {folder}/{file_name}");
+            written += 1;
+        }
+    }
+
+    println!("Imported {written} files of {year} ({unchanged} unchanged).");
+    if skipped > 0 {
+        println!("Use `--force` to overwrite the {skipped} files that differ.");
+    }
+    Ok(())
+}
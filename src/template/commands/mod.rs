@@ -0,0 +1,240 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::{self, Command},
+    str::FromStr,
+};
+
+use pico_args::{Arguments, Error};
+
+use super::{
+    bench::BenchConfig,
+    record::RecordStream,
+    runner::{self, RunOptions},
+    timings::HistoryOptions,
+};
+use crate::years::{self, DEFAULT_YEAR};
+
+pub mod all;
+pub mod download;
+pub mod inputs;
+pub mod read;
+pub mod readme;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+
+/// Set for a rebuilt `aoc` process, so that it does not rebuild itself again.
+const REBUILT_ENV: &str = "AOC_REBUILT";
+
+/// Output format of the commands that run solutions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Human,
+    /// One JSON record per line on stdout, same as `--json stdout`.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "unknown format \"{s}\", expected \"human\" or \"json\""
+            )),
+        }
+    }
+}
+
+/// Options accepted by every command of `aoc`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GlobalOptions {
+    /// `--year <year>`, [`DEFAULT_YEAR`] if not given.
+    pub year: u16,
+    /// `--release`: run solutions from an optimized build.
+    pub release: bool,
+    /// `--features <features>`: cargo features to build solutions with, e.g. `alloc-stats`.
+    pub features: Option<String>,
+    /// `--format <human|json>`
+    pub format: Format,
+}
+
+impl GlobalOptions {
+    pub fn from_args(args: &mut Arguments) -> Result<Self, Error> {
+        Ok(Self {
+            year: args
+                .opt_value_from_str(["-y", "--year"])?
+                .unwrap_or(DEFAULT_YEAR),
+            release: args.contains("--release"),
+            features: args.opt_value_from_str("--features")?,
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+        })
+    }
+
+    /// Whether this binary has to be rebuilt by cargo to honor `--release` and `--features`.
+    pub fn needs_rebuild(&self) -> bool {
+        env::var_os(REBUILT_ENV).is_none()
+            && ((self.release && cfg!(debug_assertions)) || self.features.is_some())
+    }
+
+    /// Cargo flags selecting the profile and features of these options.
+    pub fn cargo_args(&self) -> Vec<OsString> {
+        let mut args = vec![];
+        if self.release {
+            args.push("--release".into());
+        }
+        if let Some(features) = &self.features {
+            args.push("--features".into());
+            args.push(features.into());
+        }
+        args
+    }
+}
+
+/// The cargo executable that started this binary.
+pub fn cargo() -> OsString {
+    env::var_os("CARGO").unwrap_or_else(|| "cargo".into())
+}
+
+/// Builds `aoc` with the profile and features of `options` and runs it again with the same
+/// arguments, exiting with its status.
+pub fn rebuild(options: &GlobalOptions) -> ! {
+    let status = Command::new(cargo())
+        .args(["run", "--quiet", "--bin", "aoc"])
+        .args(options.cargo_args())
+        .arg("--")
+        .args(env::args_os().skip(1))
+        .env(REBUILT_ENV, "1")
+        .current_dir(Path::new(env!("CARGO_MANIFEST_DIR")))
+        .status();
+
+    match status {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Fails on arguments that were not consumed by any option, so typos do not go unnoticed.
+pub fn finish(args: Arguments) -> Result<(), Error> {
+    let rest = args.finish();
+    if rest.is_empty() {
+        return Ok(());
+    }
+    let rest = rest
+        .iter()
+        .map(|arg| arg.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ");
+    Err(invalid(&rest, "unexpected argument"))
+}
+
+fn invalid(value: &str, cause: &str) -> Error {
+    Error::Utf8ArgumentParsingFailed {
+        value: value.into(),
+        cause: cause.into(),
+    }
+}
+
+/// Handles `solve <day>`, `all`, and `bench` / `check`, which run a single day if one is given
+/// and every day otherwise.
+pub fn run(mut args: Arguments, global: &GlobalOptions, command: &str) -> Result<(), Error> {
+    let mut options = RunOptions::from_args(&mut args)?;
+    if command == "bench" && options.bench.is_none() {
+        options.bench = Some(BenchConfig::from_args(&mut args)?);
+    }
+    options.check |= command == "check";
+    if global.format == Format::Json {
+        options.records.get_or_insert(RecordStream::Stdout);
+    }
+
+    let history = HistoryOptions::from_args(&mut args)?;
+    let source: Option<String> = args.opt_value_from_str("--input")?;
+    let inputs_dir: Option<PathBuf> = args.opt_value_from_str("--inputs-dir")?;
+    let jobs: Option<usize> = args.opt_value_from_str(["-j", "--jobs"])?;
+    let day: Option<u8> = args.opt_free_from_str()?;
+    finish(args)?;
+
+    match (command, day) {
+        ("all", Some(day)) => Err(invalid(
+            &day.to_string(),
+            "`all` runs every day, use `solve <day>` instead",
+        )),
+        ("solve", None) => Err(Error::MissingArgument),
+        (_, Some(_)) if jobs.is_some() => {
+            Err(invalid("--jobs", "only used when running every day"))
+        }
+        (_, None) if source.is_some() => Err(invalid("--input", "needs a day")),
+        (_, None) if inputs_dir.is_some() => Err(invalid("--inputs-dir", "needs a day")),
+        _ if source.is_some() && inputs_dir.is_some() => {
+            Err(invalid("--inputs-dir", "cannot be combined with --input"))
+        }
+        (_, Some(day)) => {
+            let Some(solution) =
+                years::get(global.year).and_then(|year| year.days.iter().find(|d| d.day == day))
+            else {
+                eprintln!(
+                    "Day {day} of {} is not registered, create it with `cargo scaffold {day}`.",
+                    global.year
+                );
+                process::exit(1);
+            };
+            match inputs_dir {
+                Some(dir) => runner::run_inputs_dir(solution, &options, &dir),
+                None => runner::run_day(solution, &options, &history, source),
+            }
+            Ok(())
+        }
+        (_, None) => {
+            all::handle(global.year, &options, &history, jobs.unwrap_or(1));
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Arguments {
+        Arguments::from_vec(args.iter().map(OsString::from).collect())
+    }
+
+    #[test]
+    fn test_global_options() {
+        let mut args = args(&["7", "--format", "json", "--year", "2022", "--release"]);
+        let options = GlobalOptions::from_args(&mut args).unwrap();
+        assert_eq!(
+            options,
+            GlobalOptions {
+                year: 2022,
+                release: true,
+                features: None,
+                format: Format::Json,
+            }
+        );
+        assert_eq!(options.cargo_args(), vec![OsString::from("--release")]);
+        assert!(finish(args).is_err());
+
+        let mut args = self::args(&["--format", "yaml"]);
+        assert!(GlobalOptions::from_args(&mut args).is_err());
+    }
+
+    #[test]
+    fn test_finish() {
+        let mut args = args(&["--samples", "3"]);
+        assert_eq!(args.opt_value_from_str("--samples").unwrap(), Some(3));
+        assert!(finish(args).is_ok());
+    }
+}
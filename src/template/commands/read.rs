@@ -0,0 +1,47 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pico_args::{Arguments, Error};
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    process,
+};
+
+use super::GlobalOptions;
+use crate::{aoc_cli, template::markdown};
+
+/// `read <day>`: prints the puzzle description of a day.
+pub fn handle(mut args: Arguments, options: &GlobalOptions) -> Result<(), Error> {
+    let day: u8 = args.free_from_str()?;
+    super::finish(args)?;
+
+    // prefer the puzzle written by `cargo download`, so puzzles can be read offline.
+    if let Ok(puzzle) = fs::read_to_string(aoc_cli::get_puzzle_path(options.year, day)) {
+        let styled = io::stdout().is_terminal();
+        print!(
+            "{}",
+            markdown::render(&puzzle, markdown::terminal_width(), styled)
+        );
+        return Ok(());
+    }
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    match aoc_cli::read(day, Some(options.year)) {
+        Ok(cmd_output) => {
+            if !cmd_output.status.success() {
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("failed to spawn aoc-cli: {e}");
+            process::exit(1);
+        }
+    }
+    Ok(())
+}
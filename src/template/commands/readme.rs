@@ -0,0 +1,74 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pico_args::{Arguments, Error};
+use std::{fs, path::Path, process};
+
+use super::GlobalOptions;
+use crate::{
+    template::{
+        answers::Answers,
+        readme::{self, MARKER},
+        timings::History,
+    },
+    years,
+};
+
+/// `readme`: writes the stars and runtimes of every registered day into the marker-delimited
+/// section of `README.md`, next to the stars table of the `readme-stars` workflow. Runtimes are the
+/// latest benchmark of every part, so run `cargo aoc bench --check` first.
+pub fn handle(args: Arguments, options: &GlobalOptions) -> Result<(), Error> {
+    super::finish(args)?;
+
+    let Some(year) = years::get(options.year) else {
+        eprintln!("No solutions registered for year {}.", options.year);
+        process::exit(1);
+    };
+
+    let history = match History::load(&History::path(year.year)) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read timing history: {e}");
+            process::exit(1);
+        }
+    };
+    if !history.entries.iter().any(|e| e.bench) {
+        println!("No benchmarks in the timing history yet, run `cargo aoc bench` to add runtimes.");
+    }
+
+    let days: Vec<u8> = year.days.iter().map(|d| d.day).collect();
+    let rows = readme::rows(&days, &history, |day, part| {
+        Answers::load(year.year, day)
+            .ok()
+            .flatten()
+            .is_some_and(|answers| answers.get(part).is_some())
+    });
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md");
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    };
+
+    let updated = readme::replace_section(&contents, &readme::table(year.year, &rows));
+    if updated == contents {
+        println!("\"README.md\" is up to date.");
+        return Ok(());
+    }
+    if !contents.contains(MARKER) {
+        println!("No {MARKER} markers found, appending the table.");
+    }
+
+    match fs::write(&path, updated) {
+        Ok(_) => println!("🎄 Updated the runtimes table in \"README.md\"."),
+        Err(e) => {
+            eprintln!("Failed to write \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+    Ok(())
+}
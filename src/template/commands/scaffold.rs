@@ -0,0 +1,609 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pico_args::{Arguments, Error};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process,
+};
+
+use self::render::{placeholders, render};
+use super::GlobalOptions;
+use crate::{
+    aoc_cli,
+    template::{layout::Layout, markdown},
+    years::DEFAULT_YEAR,
+};
+
+mod render;
+
+/// Module templates, `templates/<name>.rs`, chosen with `--template <name>`.
+const TEMPLATE_DIR: &str = "templates";
+const DEFAULT_TEMPLATE: &str = "default";
+const DEFAULT_ANSWER_TYPE: &str = "u64";
+
+const YEARS_REGISTRY_PATH: &str = "src/years/mod.rs";
+
+fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
+
+fn create_file(path: &str) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+fn bin_contents(layout: &Layout, day_padded: &str) -> String {
+    format!(
+        "fn main() {{\n    advent_of_code::template::runner::run::<advent_of_code::{}::day{day_padded}::Solver>();\n}}\n",
+        layout.crate_path
+    )
+}
+
+fn year_module_contents(year: u16) -> String {
+    format!(
+        "use crate::template::solution::Day;\n\npub const YEAR: u16 = {year};\n\n/// All days, in order. `cargo scaffold --year {year}` adds new days here.\npub const ALL: &[Day] = &[\n];\n"
+    )
+}
+
+/// Inserts `mod_line` among the lines starting with `mod_prefix` (or after the imports) and
+/// `entry` into the registry array, both in sorted position. Days and years are zero-padded or of
+/// equal length, so sorting the lines sorts them by number.
+fn register(
+    path: &str,
+    mod_line: String,
+    mod_prefix: &str,
+    entry: String,
+) -> Result<bool, std::io::Error> {
+    let registry = fs::read_to_string(path)?;
+
+    if registry.lines().any(|l| l == mod_line) {
+        return Ok(false);
+    }
+
+    let mut lines: Vec<String> = registry
+        .replace("= &[];", "= &[\n];")
+        .lines()
+        .map(String::from)
+        .collect();
+
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(mod_prefix))
+        .collect();
+    match modules.iter().rfind(|&&i| lines[i] < mod_line) {
+        Some(&i) => lines.insert(i + 1, mod_line),
+        // before the first module, above its attributes
+        None if !modules.is_empty() => {
+            let mut i = modules[0];
+            while i > 0
+                && [" ", "#[", ")]"]
+                    .iter()
+                    .any(|p| lines[i - 1].starts_with(p))
+            {
+                i -= 1;
+            }
+            lines.insert(i, mod_line);
+        }
+        None => {
+            // first module of the registry, give it its own paragraph after the imports
+            let i = lines
+                .iter()
+                .rposition(|l| l.starts_with("use "))
+                .map_or(0, |i| i + 1);
+            lines.splice(i..i, [String::new(), mod_line]);
+        }
+    }
+
+    let end_index = lines.iter().rposition(|l| l == "];").ok_or_else(|| {
+        std::io::Error::other(format!("could not find end of registry in {path}"))
+    })?;
+    let start_index = lines[..end_index]
+        .iter()
+        .rposition(|l| l.ends_with("= &["))
+        .map_or(end_index, |i| i + 1);
+    let index = (start_index..end_index)
+        .find(|&i| lines[i] > entry)
+        .unwrap_or(end_index);
+    lines.insert(index, entry);
+
+    fs::write(path, lines.join("\n") + "\n")?;
+    Ok(true)
+}
+
+/// Adds `pub mod dayNN;` and the matching `Day` entry to the registry of the year.
+fn register_day(layout: &Layout, day_padded: &str) -> Result<bool, std::io::Error> {
+    register(
+        &layout.registry_path(),
+        format!("pub mod day{day_padded};"),
+        "pub mod day",
+        format!("    Day::of::<day{day_padded}::Solver>(),"),
+    )
+}
+
+/// Creates the module of a new year and adds it to `src/years/mod.rs`.
+fn register_year(layout: &Layout) -> Result<bool, std::io::Error> {
+    if layout.year == DEFAULT_YEAR || fs::metadata(layout.registry_path()).is_ok() {
+        return Ok(false);
+    }
+
+    fs::create_dir_all(&layout.module_dir)?;
+    fs::write(layout.registry_path(), year_module_contents(layout.year))?;
+
+    register(
+        YEARS_REGISTRY_PATH,
+        format!("pub mod y{};", layout.year),
+        "pub mod y",
+        format!("    Year {{ year: {0}, days: y{0}::ALL }},", layout.year),
+    )
+}
+
+/// Writes the code blocks of the downloaded puzzle to `src/examples`: the chosen block to
+/// `NN.txt`, all others to `NN_02.txt`, `NN_03.txt`, ... (see `read_file_alternate`).
+/// Existing non-empty example files are left untouched.
+fn extract_examples(year: u16, day: u8, chosen: Option<usize>) -> Result<Vec<String>, String> {
+    let puzzle_path = aoc_cli::get_puzzle_path(year, day);
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        return Ok(vec![]);
+    };
+
+    let mut blocks = markdown::code_blocks(&puzzle);
+    if let Some(chosen) = chosen {
+        if chosen == 0 || chosen > blocks.len() {
+            return Err(format!(
+                "\"{puzzle_path}\" has {} code blocks, cannot use block {chosen}",
+                blocks.len()
+            ));
+        }
+        let block = blocks.remove(chosen - 1);
+        blocks.insert(0, block);
+    }
+
+    let dir = crate::data_dir("examples", year);
+    fs::create_dir_all(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+
+    let mut messages = vec![];
+    for (i, block) in blocks.iter().enumerate() {
+        let path = match i {
+            0 => format!("{}/{day:02}.txt", dir.display()),
+            i => format!("{}/{day:02}_{:02}.txt", dir.display(), i + 1),
+        };
+
+        if fs::read_to_string(&path).is_ok_and(|contents| !contents.trim().is_empty()) {
+            messages.push(format!("Skipped example file \"{path}\", it is not empty"));
+            continue;
+        }
+
+        fs::write(&path, block).map_err(|e| format!("{path}: {e}"))?;
+
+        let first_line = block.lines().next().unwrap_or_default();
+        messages.push(format!(
+            "Extracted example file \"{path}\" ({} lines, starting with \"{first_line}\")",
+            block.lines().count()
+        ));
+    }
+
+    Ok(messages)
+}
+
+/// Loads `templates/<name>.rs`, listing the available templates if there is none of that name.
+fn load_template(name: &str) -> Result<String, String> {
+    let path = Path::new(TEMPLATE_DIR).join(format!("{name}.rs"));
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let mut available: Vec<String> = fs::read_dir(TEMPLATE_DIR)
+                .map_err(|e| format!("{TEMPLATE_DIR}: {e}"))?
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    (path.extension()? == "rs")
+                        .then(|| path.file_stem()?.to_str().map(String::from))?
+                })
+                .collect();
+            available.sort();
+            Err(format!(
+                "no template named \"{name}\", available: {}",
+                available.join(", ")
+            ))
+        }
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
+
+/// Replaces `part_x() => None` in the tests of `module` with the example answers
+/// proposed by the puzzle text. Tests that were already edited are left untouched; answers that are
+/// not integers are filled in as strings.
+fn fill_tests(module: &str, answers: &[Option<String>; 2]) -> (String, Vec<String>) {
+    let mut module = module.to_string();
+    let mut messages = vec![];
+
+    for (i, (func, answer)) in ["part_one", "part_two"].iter().zip(answers).enumerate() {
+        let Some(answer) = answer else {
+            messages.push(format!("No example answer found for part {}", i + 1));
+            continue;
+        };
+
+        let placeholder = format!("{func}() => None;");
+        if !module.contains(&placeholder) {
+            messages.push(format!(
+                "Skipped test for part {}, it was already edited (proposed: {answer})",
+                i + 1
+            ));
+            continue;
+        }
+
+        let expected = match answer.parse::<i128>() {
+            Ok(_) => answer.clone(),
+            Err(_) => format!("{answer:?}"),
+        };
+        module = module.replace(&placeholder, &format!("{func}() => Some({expected});"));
+        messages.push(format!(
+            "Filled in example answer for part {}: {answer}",
+            i + 1
+        ));
+    }
+
+    (module, messages)
+}
+
+/// Returns `None` if the puzzle has not been downloaded.
+fn proposed_answers(year: u16, day: u8) -> Option<[Option<String>; 2]> {
+    fs::read_to_string(aoc_cli::get_puzzle_path(year, day))
+        .ok()
+        .map(|puzzle| markdown::example_answers(&puzzle))
+}
+
+fn update_tests(year: u16, day: u8, module_path: &str) {
+    let module = match fs::read_to_string(module_path) {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to read module file \"{module_path}\": {e}");
+            process::exit(1);
+        }
+    };
+
+    let Some(answers) = proposed_answers(year, day) else {
+        eprintln!(
+            "Puzzle \"{}\" not found. Try running `cargo download {day} --year {year}` first.",
+            aoc_cli::get_puzzle_path(year, day)
+        );
+        process::exit(1);
+    };
+
+    let (module, messages) = fill_tests(&module, &answers);
+    for message in messages {
+        println!("{message}");
+    }
+
+    if let Err(e) = fs::write(module_path, module) {
+        eprintln!("Failed to write module file: {e}");
+        process::exit(1);
+    }
+}
+
+/// `scaffold <day>`: creates the module, binary, input and example files of a day and registers
+/// it. `--example <n>` picks the puzzle code block used as the main example, `--update-tests` only
+/// fills in the expected example answers of an existing module.
+///
+/// The module is generated from `templates/<name>.rs` (`--template <name>`, `default` if not
+/// given), with `{{day}}`, `{{day_padded}}`, `{{year}}` and `{{answer_type}}` substituted. The
+/// answer type is `u64` unless given with `--answer-type <type>`.
+pub fn handle(mut args: Arguments, options: &GlobalOptions) -> Result<(), Error> {
+    let example: Option<usize> = args.opt_value_from_str(["-e", "--example"])?;
+    let update_tests_only = args.contains("--update-tests");
+    let template: Option<String> = args.opt_value_from_str(["-t", "--template"])?;
+    let answer_type: Option<String> = args.opt_value_from_str("--answer-type")?;
+    let day = args.free_from_fn(parse_day)?;
+    super::finish(args)?;
+
+    if update_tests_only {
+        let module_path = Layout::new(options.year).module_path(&format!("{day:02}"));
+        update_tests(options.year, day, &module_path);
+        return Ok(());
+    }
+
+    let template = template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+    let answer_type = answer_type.as_deref().unwrap_or(DEFAULT_ANSWER_TYPE);
+    let module = match load_template(template)
+        .and_then(|t| render(&t, &placeholders(day, options.year, answer_type)))
+    {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+
+    scaffold(day, options.year, example, module);
+    Ok(())
+}
+
+/// Rejects days without a puzzle before any file is written.
+fn parse_day(s: &str) -> Result<u8, String> {
+    s.parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("invalid day \"{s}\", expected 1 to 25"))
+}
+
+fn scaffold(day: u8, year: u16, example: Option<usize>, module: String) {
+    let day_padded = format!("{day:02}");
+    let layout = Layout::new(year);
+
+    let input_dir = crate::data_dir("inputs", year);
+    let example_dir = crate::data_dir("examples", year);
+    let input_path = format!("{}/{day_padded}.txt", input_dir.display());
+    let example_path = format!("{}/{day_padded}.txt", example_dir.display());
+    let module_path = layout.module_path(&day_padded);
+    let bin_path = layout.bin_path(&day_padded);
+
+    match register_year(&layout) {
+        Ok(true) => {
+            println!(
+                "Created year module \"{}\" and registered it in \"{}\"",
+                layout.registry_path(),
+                YEARS_REGISTRY_PATH
+            );
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to register year: {e}");
+            process::exit(1);
+        }
+    }
+
+    let mut file = match safe_create_file(&module_path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create module file: {e}");
+            process::exit(1);
+        }
+    };
+
+    let (module, test_messages) = match proposed_answers(year, day) {
+        Some(answers) => fill_tests(&module, &answers),
+        None => (module, vec![]),
+    };
+
+    match file.write_all(module.as_bytes()) {
+        Ok(_) => {
+            println!("Created module file \"{}\"", module_path);
+            for message in test_messages {
+                println!("  {message}");
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to write module contents: {e}");
+            process::exit(1);
+        }
+    }
+
+    match safe_create_file(&bin_path)
+        .and_then(|mut f| f.write_all(bin_contents(&layout, &day_padded).as_bytes()))
+    {
+        Ok(_) => {
+            println!("Created binary file \"{}\"", bin_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create binary file: {e}");
+            process::exit(1);
+        }
+    }
+
+    match register_day(&layout, &day_padded) {
+        Ok(true) => {
+            println!("Registered day in \"{}\"", layout.registry_path());
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to register day: {e}");
+            process::exit(1);
+        }
+    }
+
+    match fs::create_dir_all(&input_dir).and_then(|_| create_file(&input_path)) {
+        Ok(_) => {
+            println!("Created empty input file \"{}\"", input_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create input file: {e}");
+            process::exit(1);
+        }
+    }
+
+    match extract_examples(year, day, example) {
+        Ok(messages) if !messages.is_empty() => {
+            for message in messages {
+                println!("{message}");
+            }
+        }
+        Ok(_) => match fs::create_dir_all(&example_dir).and_then(|_| create_file(&example_path)) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
+        },
+        Err(e) => {
+            eprintln!("Failed to extract examples: {e}");
+            process::exit(1);
+        }
+    }
+
+    println!("---");
+    match year {
+        DEFAULT_YEAR => println!("🎄 Type `cargo solve {day}` to run your solution."),
+        _ => println!("🎄 Type `cargo solve {day} --year {year}` to run your solution."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The shipped templates rendered by the build script for day 0, which has an empty example
+    /// like a new day. Built with the tests so that a template which does not compile fails here
+    /// rather than in a freshly scaffolded day.
+    mod rendered {
+        const YEAR: u16 = 2023;
+
+        pub mod default {
+            include!(concat!(env!("OUT_DIR"), "/templates/default.rs"));
+        }
+        pub mod nom_grid {
+            include!(concat!(env!("OUT_DIR"), "/templates/nom_grid.rs"));
+        }
+        pub mod nom_lines {
+            include!(concat!(env!("OUT_DIR"), "/templates/nom_lines.rs"));
+        }
+    }
+
+    #[test]
+    fn test_register_sorted() {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let days = dir.join("days.rs");
+        let years = dir.join("years.rs");
+        fs::write(
+            &days,
+            "use crate::template::solution::Day;\n\npub mod day03;\n#[allow(\n    dead_code\n)]\npub mod day05;\n\npub const ALL: &[Day] = &[\n    Day::of::<day03::Solver>(),\n    Day::of::<day05::Solver>(),\n];\n",
+        )
+        .unwrap();
+        fs::write(
+            &years,
+            "use crate::days;\n\n#[rustfmt::skip]\npub const ALL: &[Year] = &[\n    Year { year: 2023, days: days::ALL },\n];\n",
+        )
+        .unwrap();
+
+        let register_day = |day: &str| {
+            register(
+                days.to_str().unwrap(),
+                format!("pub mod day{day};"),
+                "pub mod day",
+                format!("    Day::of::<day{day}::Solver>(),"),
+            )
+            .unwrap()
+        };
+        assert!(register_day("04"));
+        assert!(register_day("01"));
+        assert!(register_day("12"));
+        assert!(!register_day("04"));
+        let register_year = |year: u16| {
+            register(
+                years.to_str().unwrap(),
+                format!("pub mod y{year};"),
+                "pub mod y",
+                format!("    Year {{ year: {year}, days: y{year}::ALL }},"),
+            )
+            .unwrap()
+        };
+        assert!(register_year(2024));
+        assert!(register_year(2021));
+        assert!(register_year(2022));
+
+        let (days, years) = (
+            fs::read_to_string(days).unwrap(),
+            fs::read_to_string(years).unwrap(),
+        );
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            days,
+            "use crate::template::solution::Day;\n\npub mod day01;\npub mod day03;\npub mod day04;\n#[allow(\n    dead_code\n)]\npub mod day05;\npub mod day12;\n\npub const ALL: &[Day] = &[\n    Day::of::<day01::Solver>(),\n    Day::of::<day03::Solver>(),\n    Day::of::<day04::Solver>(),\n    Day::of::<day05::Solver>(),\n    Day::of::<day12::Solver>(),\n];\n"
+        );
+        assert_eq!(
+            years,
+            "use crate::days;\n\npub mod y2021;\npub mod y2022;\npub mod y2024;\n\n#[rustfmt::skip]\npub const ALL: &[Year] = &[\n    Year { year: 2021, days: y2021::ALL },\n    Year { year: 2022, days: y2022::ALL },\n    Year { year: 2023, days: days::ALL },\n    Year { year: 2024, days: y2024::ALL },\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("1"), Ok(1));
+        assert_eq!(parse_day("25"), Ok(25));
+        assert!(parse_day("0").is_err());
+        assert!(parse_day("26").is_err());
+        assert!(parse_day("x").is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let values = placeholders(7, 2022, "usize");
+        assert_eq!(
+            render(
+                "solution!({{day}}, {{ answer_type }}); // {{year}}/{{day_padded}}",
+                &values
+            ),
+            Ok("solution!(7, usize); // 2022/07".into())
+        );
+        assert_eq!(
+            render("no placeholders {}", &values),
+            Ok("no placeholders {}".into())
+        );
+        assert_eq!(
+            render(
+                r#"format!("\{{}}{}\{{ {{day}} }}", {{day_padded}}); \{{day}}"#,
+                &values
+            ),
+            Ok(r#"format!("{{}}{}{{ 7 }}", 07); {{day}}"#.into())
+        );
+        assert!(render("{{days}}", &values).is_err());
+        assert!(render("format!(\"{{}}\")", &values).is_err());
+        assert!(render("{{day", &values).is_err());
+    }
+
+    #[test]
+    fn test_shipped_templates() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(TEMPLATE_DIR);
+        let templates = fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect::<Vec<_>>();
+        assert!(templates.len() >= 3);
+
+        for path in templates {
+            let template = fs::read_to_string(&path).unwrap();
+            let module = render(&template, &placeholders(3, 2023, "u64")).unwrap();
+            assert!(
+                module.starts_with("crate::solution!(3, u64);"),
+                "{}",
+                path.display()
+            );
+
+            let (_, messages) = fill_tests(&module, &[Some("42".into()), Some("ABC".into())]);
+            assert!(
+                messages.iter().all(|m| m.starts_with("Filled in")),
+                "{}: {messages:?}",
+                path.display()
+            );
+        }
+
+        type Part = fn(&str) -> Option<u64>;
+        let rendered: [(&str, [Part; 2]); 3] = [
+            (
+                "default",
+                [rendered::default::part_one, rendered::default::part_two],
+            ),
+            (
+                "nom-grid",
+                [rendered::nom_grid::part_one, rendered::nom_grid::part_two],
+            ),
+            (
+                "nom-lines",
+                [rendered::nom_lines::part_one, rendered::nom_lines::part_two],
+            ),
+        ];
+        for (name, parts) in rendered {
+            // the example file of a new day is empty until the puzzle is out
+            for part in parts {
+                assert_eq!(part(""), None, "{name}");
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Rendering of the module templates. Only uses `std`, as the build script renders the shipped
+//! templates with it too (see `build.rs`).
+
+/// Substitutes the `{{name}}` placeholders of `template`. Unknown or unclosed placeholders are
+/// errors, so typos in a template do not end up in the generated module. A literal `{{` (e.g. in
+/// `format!("{{")`) is written as `\{{`.
+pub fn render(template: &str, values: &[(&str, String)]) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        if let Some(before) = rest[..start].strip_suffix('\\') {
+            rendered += before;
+            rendered += "{{";
+            rest = after;
+            continue;
+        }
+        rendered += &rest[..start];
+        let end = after.find("}}").ok_or_else(|| {
+            format!(
+                "unclosed placeholder: `{{{{{}`",
+                after.lines().next().unwrap_or_default()
+            )
+        })?;
+        let name = after[..end].trim();
+        let value = values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .ok_or_else(|| format!("unknown placeholder `{{{{{name}}}}}`"))?;
+        rendered += &value.1;
+        rest = &after[end + 2..];
+    }
+
+    rendered += rest;
+    Ok(rendered)
+}
+
+/// The values of the placeholders available to templates.
+pub fn placeholders(day: u8, year: u16, answer_type: &str) -> [(&'static str, String); 4] {
+    [
+        ("day", day.to_string()),
+        ("day_padded", format!("{day:02}")),
+        ("year", year.to_string()),
+        ("answer_type", answer_type.to_string()),
+    ]
+}
@@ -0,0 +1,118 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pico_args::{Arguments, Error};
+use std::process;
+
+use super::GlobalOptions;
+use crate::{
+    aoc_cli,
+    template::{
+        answer::Answer,
+        answers::Answers,
+        submit::{self, History, Verdict},
+    },
+    years,
+};
+
+/// Runs the part in-process to get the answer that `cargo solve` would print.
+fn solve(year: u16, day: u8, part: u8) -> Option<Answer> {
+    let solution = years::get(year)?.days.iter().find(|d| d.day == day)?;
+    let input = &match crate::try_read_file("inputs", year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {e}");
+            return None;
+        }
+    };
+    let solver = match part {
+        1 => solution.part_one,
+        _ => solution.part_two,
+    };
+    crate::solve!(year, day, part, solver, input).answer
+}
+
+/// `submit <day> <part>`: submits the answer of a part, or `--answer <answer>`, and shows the
+/// previous guesses. `--history` only shows the guesses.
+pub fn handle(mut args: Arguments, options: &GlobalOptions) -> Result<(), Error> {
+    let history_only = args.contains("--history");
+    let answer: Option<Answer> = args.opt_value_from_str(["-a", "--answer"])?;
+    let day: u8 = args.free_from_str()?;
+    let part: u8 = args.free_from_str()?;
+    super::finish(args)?;
+    if part != 1 && part != 2 {
+        return Err(super::invalid(&part.to_string(), "part must be 1 or 2"));
+    }
+
+    submit_part(options.year, day, part, answer, history_only);
+    Ok(())
+}
+
+fn submit_part(year: u16, day: u8, part: u8, answer: Option<Answer>, history_only: bool) {
+    let history_path = History::path(year, day);
+    let history = match History::load(&history_path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read submission history: {e}");
+            process::exit(1);
+        }
+    };
+
+    let guesses = history.for_part(part).collect::<Vec<_>>();
+    if !guesses.is_empty() {
+        println!("Previous guesses for part {}:", part);
+        for guess in guesses {
+            println!("  {:>12}  {}", guess.verdict, guess.answer);
+        }
+        println!("Bounds: {}", history.bounds(part));
+        println!("---");
+    }
+
+    if history_only {
+        return;
+    }
+
+    let answer = match answer.or_else(|| solve(year, day, part)) {
+        Some(answer) => answer,
+        None => {
+            eprintln!("Part {} of day {} is not solved.", part, day);
+            process::exit(1);
+        }
+    };
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    let aoc = aoc_cli::command();
+    match submit::submit(
+        &aoc,
+        day,
+        Some(year),
+        part,
+        &answer.to_string(),
+        &history_path,
+    ) {
+        Ok(Verdict::Correct) => match Answers::record(year, day, part, &answer) {
+            Ok(path) => println!("🎄 Recorded answer in \"{}\".", path.display()),
+            Err(e) => {
+                eprintln!("Failed to record answer: {e}");
+                process::exit(1);
+            }
+        },
+        Ok(verdict) => {
+            println!("---");
+            println!("🎄 Response: {verdict}");
+            if verdict.is_wrong() {
+                let history = History::load(&history_path).unwrap_or_default();
+                println!("Bounds: {}", history.bounds(part));
+            }
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,320 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pico_args::{Arguments, Error};
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use super::{Format, GlobalOptions};
+use crate::template::layout::Layout;
+
+struct Args {
+    day: u8,
+    /// Polling interval.
+    interval: Duration,
+    /// Passed on to `solve`, e.g. `--bench`.
+    solve_args: Vec<OsString>,
+}
+
+/// Modification times of the watched files, `None` for files that do not exist (yet).
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// The files a day depends on: its binary and module, the shared modules directly in `src/`, the
+/// template code, and its input and example files.
+fn watched_files(root: &Path, layout: &Layout, day: u8) -> Vec<PathBuf> {
+    let day_padded = format!("{day:02}");
+    let mut files = vec![
+        root.join(layout.bin_path(&day_padded)),
+        root.join(layout.module_path(&day_padded)),
+        root.join(crate::data_dir("inputs", layout.year))
+            .join(format!("{day_padded}.txt")),
+    ];
+
+    files.extend(files_in(&root.join("src"), |path| {
+        has_extension(path, "rs")
+    }));
+    files.extend(files_in(&root.join("src/template"), |path| {
+        has_extension(path, "rs")
+    }));
+    files.extend(files_in(
+        &root.join(crate::data_dir("examples", layout.year)),
+        |path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&day_padded))
+        },
+    ));
+
+    files.sort();
+    files.dedup();
+    files
+}
+
+fn files_in(dir: &Path, filter: impl Fn(&Path) -> bool) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && filter(path))
+        .collect()
+}
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension().is_some_and(|ext| ext == extension)
+}
+
+fn snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+fn changed_files<'a>(old: &Snapshot, new: &'a Snapshot) -> Vec<&'a Path> {
+    new.iter()
+        .filter(|(path, modified)| old.get(*path) != Some(modified))
+        .map(|(path, _)| path.as_path())
+        .collect()
+}
+
+/// Runs a cargo command in the crate root and prints how long it took.
+fn cargo(root: &Path, label: &str, args: &[OsString]) -> bool {
+    println!("--- {label} ---");
+    let start = Instant::now();
+    let status = Command::new(super::cargo())
+        .args(args)
+        .current_dir(root)
+        .status();
+    let elapsed = start.elapsed();
+
+    match status {
+        Ok(status) => {
+            let verdict = if status.success() { "✅" } else { "❌" };
+            println!("{verdict} {label} finished in {elapsed:.2?}");
+            status.success()
+        }
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            false
+        }
+    }
+}
+
+fn run(
+    root: &Path,
+    layout: &Layout,
+    args: &Args,
+    options: &GlobalOptions,
+    run_count: usize,
+    reason: &str,
+) {
+    let day_padded = format!("{:02}", args.day);
+    println!();
+    println!("=== Run #{run_count} · day {day_padded} · {reason} ===");
+
+    let mut test_args: Vec<OsString> = vec!["test".into(), "--lib".into(), "--quiet".into()];
+    test_args.extend(options.cargo_args());
+    test_args.push("--".into());
+    test_args.push(format!("{}::day{day_padded}::", layout.crate_path).into());
+    let tests_passed = cargo(root, "Tests", &test_args);
+
+    let mut solve_args: Vec<OsString> =
+        vec!["run".into(), "--quiet".into(), "--bin".into(), "aoc".into()];
+    solve_args.extend(options.cargo_args());
+    solve_args.extend(
+        [
+            "--",
+            "solve",
+            &day_padded,
+            "--year",
+            &options.year.to_string(),
+        ]
+        .map(OsString::from),
+    );
+    if options.format == Format::Json {
+        solve_args.extend(["--format", "json"].map(OsString::from));
+    }
+    solve_args.extend(args.solve_args.iter().cloned());
+    let solved = cargo(root, "Solution", &solve_args);
+
+    let summary = match (tests_passed, solved) {
+        (true, true) => "✅ all good",
+        (false, _) => "❌ tests failed",
+        (true, false) => "❌ solution failed",
+    };
+    println!("=== {summary}, watching for changes… ===");
+}
+
+/// `watch <day>`: reruns the tests and the solution of a day whenever one of its files changes.
+/// `--interval <ms>` sets the polling interval, arguments after `--` are passed on to `solve`.
+pub fn handle(mut args: Arguments, options: &GlobalOptions) -> Result<(), Error> {
+    let interval = args.opt_value_from_str("--interval")?.unwrap_or(500);
+    let day = args.free_from_str()?;
+    let mut solve_args = args.finish();
+    if solve_args.first().is_some_and(|arg| arg == "--") {
+        solve_args.remove(0);
+    }
+    let args = Args {
+        day,
+        interval: Duration::from_millis(interval),
+        solve_args,
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let layout = Layout::new(options.year);
+    let module_path = root.join(layout.module_path(&format!("{:02}", args.day)));
+    if !module_path.exists() {
+        eprintln!(
+            "Day {} does not exist yet, create it with `cargo scaffold {}`.",
+            args.day, args.day
+        );
+        process::exit(1);
+    }
+
+    let mut files = watched_files(root, &layout, args.day);
+    let mut last = snapshot(&files);
+    let mut run_count = 1;
+    run(root, &layout, &args, options, run_count, "initial run");
+
+    loop {
+        thread::sleep(args.interval);
+
+        // New example or helper files are picked up on the next poll.
+        files = watched_files(root, &layout, args.day);
+        let current = snapshot(&files);
+        let changed = changed_files(&last, &current);
+        if changed.is_empty() {
+            continue;
+        }
+
+        let reason = match changed.as_slice() {
+            [path] => format!(
+                "{} changed",
+                path.strip_prefix(root).unwrap_or(path).display()
+            ),
+            _ => format!("{} files changed", changed.len()),
+        };
+
+        // Editors often write a file in several steps, so wait for the changes to settle.
+        thread::sleep(args.interval);
+        last = snapshot(&files);
+        run_count += 1;
+        run(root, &layout, &args, options, run_count, &reason);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A crate root in the temp dir, removed again on drop.
+    struct TempRoot(PathBuf);
+
+    impl TempRoot {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!("aoc-watch-{name}-{}", process::id()));
+            for (path, contents) in [
+                ("src/lib.rs", ""),
+                ("src/notes.md", ""),
+                ("src/template/runner.rs", ""),
+                ("src/days/day03.rs", ""),
+                ("src/examples/03.txt", ""),
+                ("src/examples/03_02.txt", ""),
+                ("src/examples/04.txt", ""),
+                ("src/examples/2022/03.txt", ""),
+            ] {
+                let path = root.join(path);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(path, contents).unwrap();
+            }
+            Self(root)
+        }
+    }
+
+    impl Drop for TempRoot {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn relative(root: &Path, files: &[PathBuf]) -> Vec<String> {
+        files
+            .iter()
+            .map(|file| file.strip_prefix(root).unwrap().display().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_watched_files() {
+        let root = TempRoot::new("files");
+        let root = root.0.as_path();
+
+        assert_eq!(
+            relative(root, &watched_files(root, &Layout::new(2023), 3)),
+            [
+                "src/bin/03.rs",
+                "src/days/day03.rs",
+                "src/examples/03.txt",
+                "src/examples/03_02.txt",
+                "src/inputs/03.txt",
+                "src/lib.rs",
+                "src/template/runner.rs",
+            ]
+        );
+        assert_eq!(
+            relative(root, &watched_files(root, &Layout::new(2022), 3)),
+            [
+                "src/bin/2022_03.rs",
+                "src/examples/2022/03.txt",
+                "src/inputs/2022/03.txt",
+                "src/lib.rs",
+                "src/template/runner.rs",
+                "src/years/y2022/day03.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changed_files() {
+        let root = TempRoot::new("changes");
+        let files = watched_files(&root.0, &Layout::new(2023), 3);
+        let before = snapshot(&files);
+        assert!(changed_files(&before, &snapshot(&files)).is_empty());
+
+        let example = root.0.join("src/examples/03.txt");
+        let modified = fs::metadata(&example).unwrap().modified().unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&example)
+            .unwrap()
+            .set_modified(modified + Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(
+            changed_files(&before, &snapshot(&files)),
+            [example.as_path()]
+        );
+
+        // a file that appears counts as changed too
+        let input = root.0.join("src/inputs/03.txt");
+        fs::create_dir_all(input.parent().unwrap()).unwrap();
+        fs::write(&input, "").unwrap();
+        let after = snapshot(&files);
+        assert_eq!(
+            changed_files(&before, &after),
+            [example.as_path(), input.as_path()]
+        );
+        assert!(changed_files(&after, &snapshot(&files)).is_empty());
+    }
+}
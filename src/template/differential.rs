@@ -0,0 +1,256 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    borrow::Borrow,
+    fmt::{self, Debug, Display},
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use super::{input, runner::panic_message};
+
+/// Longest input printed in a [`Divergence`], longer inputs are cut off.
+const MAX_INPUT_LEN: usize = 2000;
+
+/// What an implementation returned for an input, or the message it panicked with.
+pub type Outcome<O> = Result<O, String>;
+
+/// The first input on which two implementations disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence<I, O> {
+    /// Position of the input in the compared inputs.
+    pub index: usize,
+    pub input: I,
+    pub reference: Outcome<O>,
+    pub candidate: Outcome<O>,
+}
+
+impl<I: Debug, O: Debug> Display for Divergence<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut input = format!("{:?}", self.input);
+        if input.len() > MAX_INPUT_LEN {
+            let end = input.floor_char_boundary(MAX_INPUT_LEN);
+            input.truncate(end);
+            input.push_str("...");
+        }
+        let outcome = |outcome: &Outcome<O>| match outcome {
+            Ok(output) => format!("{output:?}"),
+            Err(message) => format!("panicked: {message}"),
+        };
+
+        writeln!(f, "implementations diverge on input #{}:", self.index)?;
+        writeln!(f, "{input}")?;
+        writeln!(f, "reference: {}", outcome(&self.reference))?;
+        write!(f, "candidate: {}", outcome(&self.candidate))
+    }
+}
+
+fn run<Q: ?Sized, O>(func: impl Fn(&Q) -> O, input: &Q) -> Outcome<O> {
+    panic::catch_unwind(AssertUnwindSafe(|| func(input))).map_err(panic_message)
+}
+
+/// Runs a slow `reference` implementation and a `candidate` rewrite of it on every input, until
+/// their outputs differ. A panic counts as an output, so a candidate that panics where the
+/// reference does not diverges as well.
+///
+/// Returns the number of inputs compared, or the first divergence.
+pub fn compare<I, Q, O>(
+    reference: impl Fn(&Q) -> O,
+    candidate: impl Fn(&Q) -> O,
+    inputs: impl IntoIterator<Item = I>,
+) -> Result<usize, Divergence<I, O>>
+where
+    I: Borrow<Q>,
+    Q: ?Sized,
+    O: PartialEq,
+{
+    let mut count = 0;
+    for (index, input) in inputs.into_iter().enumerate() {
+        let expected = run(&reference, input.borrow());
+        let actual = run(&candidate, input.borrow());
+        if expected != actual {
+            return Err(Divergence {
+                index,
+                input,
+                reference: expected,
+                candidate: actual,
+            });
+        }
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// [`compare`]s two implementations and panics on the first divergence, for use in `#[test]`s
+/// (see day 12):
+///
+/// ```ignore
+/// differential::assert_equivalent(
+///     count_by_brute_force,
+///     |line: &str| ConditionRecord::from(line, 1).count_ways_to_solve_record_recursively(),
+///     differential::generate(500, 12, random_record),
+/// );
+/// ```
+#[track_caller]
+pub fn assert_equivalent<I, Q, O>(
+    reference: impl Fn(&Q) -> O,
+    candidate: impl Fn(&Q) -> O,
+    inputs: impl IntoIterator<Item = I>,
+) where
+    I: Borrow<Q> + Debug,
+    Q: ?Sized,
+    O: PartialEq + Debug,
+{
+    match compare(reference, candidate, inputs) {
+        Ok(0) => panic!("no inputs to compare"),
+        Ok(_) => {}
+        Err(divergence) => panic!("{divergence}"),
+    }
+}
+
+/// Reads files (relative to the working directory) as [normalized](input::normalize) inputs.
+/// Panics if a file cannot be read.
+pub fn files<P: AsRef<Path>>(paths: impl IntoIterator<Item = P>) -> Vec<String> {
+    paths
+        .into_iter()
+        .map(|path| {
+            let path = path.as_ref();
+            let contents = fs::read_to_string(path)
+                .unwrap_or_else(|e| panic!("could not read \"{}\": {e}", path.display()));
+            input::normalize(&contents)
+        })
+        .collect()
+}
+
+/// Every data file of a day in `folder`: `NN.txt` and the alternate files `NN_VV.txt`, in that
+/// order. Missing files are skipped, so this is empty for a day without data.
+pub fn data_files(folder: &str, year: u16, day: u8) -> Vec<String> {
+    let alternates =
+        (1..=99).map(|version| crate::try_read_file_alternate(folder, year, day, version));
+    std::iter::once(crate::try_read_file(folder, year, day))
+        .chain(alternates)
+        .filter_map(Result::ok)
+        .collect()
+}
+
+/// Generates `count` inputs with `generator`, from a [`Rng`] seeded with `seed` so failures are
+/// reproducible.
+pub fn generate<I>(
+    count: usize,
+    seed: u64,
+    mut generator: impl FnMut(&mut Rng) -> I,
+) -> impl Iterator<Item = I> {
+    let mut rng = Rng::new(seed);
+    (0..count).map(move |_| generator(&mut rng))
+}
+
+/// Small deterministic random number generator (SplitMix64) for generating inputs. Not suitable
+/// for anything but tests.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`. Panics if `bound` is zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "bound must not be zero");
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// A number in `min..=max`.
+    pub fn between(&mut self, min: usize, max: usize) -> usize {
+        min + self.below(max - min + 1)
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum_slow(input: &str) -> u64 {
+        input.lines().map(|line| line.parse::<u64>().unwrap()).sum()
+    }
+
+    fn sum_wrapping(input: &str) -> u64 {
+        input
+            .lines()
+            .map(|line| line.parse::<u8>().unwrap() as u64)
+            .sum()
+    }
+
+    #[test]
+    fn test_compare() {
+        let inputs = ["1\n2\n", "200\n", "300\n", "1\n"].map(String::from);
+        let divergence = compare(sum_slow, sum_wrapping, inputs.clone()).unwrap_err();
+        assert_eq!(divergence.index, 2);
+        assert_eq!(divergence.input, "300\n");
+        assert_eq!(divergence.reference, Ok(300));
+        assert!(divergence.candidate.is_err());
+
+        assert_eq!(compare(sum_slow, sum_slow, inputs), Ok(4));
+        assert_eq!(compare(|n: &u32| n * 2, |n: &u32| n << 1, 0..100), Ok(100));
+    }
+
+    #[test]
+    fn test_divergence_display() {
+        let divergence = compare(|n: &u32| n / 2, |n: &u32| n >> 2, [4, 6]).unwrap_err();
+        assert_eq!(
+            divergence.to_string(),
+            "implementations diverge on input #0:\n4\nreference: 2\ncandidate: 1"
+        );
+
+        let divergence = Divergence {
+            index: 1,
+            input: "é".repeat(MAX_INPUT_LEN),
+            reference: Ok(1),
+            candidate: Err("boom".to_string()),
+        };
+        let message = divergence.to_string();
+        assert!(message.contains("...\nreference: 1\ncandidate: panicked: boom"));
+    }
+
+    #[test]
+    #[should_panic(expected = "implementations diverge on input #1")]
+    fn test_assert_equivalent() {
+        assert_equivalent(|n: &u32| n + 1, |n: &u32| n | 1, [0, 1]);
+    }
+
+    #[test]
+    fn test_inputs() {
+        let examples = data_files("examples", crate::years::DEFAULT_YEAR, 8);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(
+            examples,
+            files([
+                crate::get_file_path("examples", crate::years::DEFAULT_YEAR, 8),
+                crate::get_file_path_alternate("examples", crate::years::DEFAULT_YEAR, 8, 2),
+            ])
+        );
+
+        let generated: Vec<usize> = generate(50, 7, |rng| rng.between(3, 5)).collect();
+        assert_eq!(
+            generated,
+            generate(50, 7, |rng| rng.between(3, 5)).collect::<Vec<_>>()
+        );
+        assert!(generated.iter().all(|n| (3..=5).contains(n)));
+        assert!((3..=5).all(|n| generated.contains(&n)));
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// Normalizes an input: LF line endings only, no trailing whitespace on any line, no trailing
+/// blank lines and exactly one trailing newline (none for an empty input). Leading whitespace is
+/// kept, as it can be significant, e.g. in grids.
+///
+/// Applied by [`read_file`](crate::read_file) and the other loaders unless the `_raw` variant or
+/// `--raw-input` is used.
+pub fn normalize(input: &str) -> String {
+    let mut normalized = String::with_capacity(input.len() + 1);
+    for line in input.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+
+    let len = normalized.trim_end_matches('\n').len();
+    normalized.truncate(len);
+    if len > 0 {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Splits a normalized input into its blocks, separated by one or more blank lines. Blocks have no
+/// leading or trailing newlines.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+/// Splits a normalized input into its first block and the rest, for inputs made of a header and a
+/// body such as a list of rules followed by a list of parts.
+pub fn split_block(input: &str) -> Option<(&str, &str)> {
+    let input = input.trim_start_matches('\n');
+    let (first, rest) = input.split_once("\n\n")?;
+    Some((first, rest.trim_matches('\n')))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a \r\nb\t\r\n\r\n\r\n"), "a\nb\n");
+        assert_eq!(normalize("  #.\n .#"), "  #.\n .#\n");
+        assert_eq!(normalize("a\n\n\nb\n"), "a\n\n\nb\n");
+        assert_eq!(normalize("\n \n"), "");
+        assert_eq!(normalize(""), "");
+
+        let normalized = normalize("x\r\ny  \n");
+        assert_eq!(normalize(&normalized), normalized);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = normalize("#.#\n..#\r\n\r\n\r\n#..\n\n");
+        assert_eq!(blocks(&input).collect::<Vec<_>>(), ["#.#\n..#", "#.."]);
+        assert_eq!(blocks("").count(), 0);
+    }
+
+    #[test]
+    fn test_split_block() {
+        assert_eq!(
+            split_block("rules\nmore\n\n\nparts\n"),
+            Some(("rules\nmore", "parts"))
+        );
+        assert_eq!(split_block("single\nblock\n"), None);
+    }
+}
@@ -0,0 +1,51 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::years::DEFAULT_YEAR;
+
+/// Where the code of a year lives. The default year keeps its days in `src/days` and its binaries
+/// in `src/bin/NN.rs`, other years use `src/years/y<year>` and `src/bin/<year>_NN.rs`.
+/// Data files are placed by [`data_dir`](crate::data_dir).
+pub struct Layout {
+    pub year: u16,
+    pub module_dir: String,
+    pub crate_path: String,
+    pub bin_prefix: String,
+}
+
+impl Layout {
+    pub fn new(year: u16) -> Self {
+        if year == DEFAULT_YEAR {
+            Self {
+                year,
+                module_dir: "src/days".into(),
+                crate_path: "days".into(),
+                bin_prefix: String::new(),
+            }
+        } else {
+            Self {
+                year,
+                module_dir: format!("src/years/y{year}"),
+                crate_path: format!("years::y{year}"),
+                bin_prefix: format!("{year}_"),
+            }
+        }
+    }
+
+    pub fn registry_path(&self) -> String {
+        format!("{}/mod.rs", self.module_dir)
+    }
+
+    pub fn module_path(&self, day_padded: &str) -> String {
+        format!("{}/day{day_padded}.rs", self.module_dir)
+    }
+
+    pub fn bin_name(&self, day_padded: &str) -> String {
+        format!("{}{day_padded}", self.bin_prefix)
+    }
+
+    pub fn bin_path(&self, day_padded: &str) -> String {
+        format!("src/bin/{}.rs", self.bin_name(day_padded))
+    }
+}
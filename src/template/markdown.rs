@@ -0,0 +1,512 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env, fs,
+    process::{Command, Stdio},
+};
+
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_CODE: &str = "\x1b[36m";
+
+/// Renders the markdown puzzle descriptions written by `cargo download` for the terminal.
+///
+/// Supports the subset aoc-cli produces: headings (ATX and setext), paragraphs, lists, fenced
+/// code blocks, emphasis, inline code and links.
+pub fn render(markdown: &str, width: usize, styled: bool) -> String {
+    let width = width.max(20);
+    let mut out = String::new();
+
+    for block in parse_blocks(markdown) {
+        match block {
+            Block::Heading(text) => {
+                let words = inline_words(&text, Style::BOLD);
+                out.push_str(&wrap(&words, width, "", "", styled));
+            }
+            Block::Paragraph(text) => {
+                let words = inline_words(&text, Style::PLAIN);
+                out.push_str(&wrap(&words, width, "", "", styled));
+            }
+            Block::ListItem { marker, text } => {
+                let words = inline_words(&text, Style::PLAIN);
+                let first = format!("  {marker} ");
+                let rest = " ".repeat(first.chars().count());
+                out.push_str(&wrap(&words, width, &first, &rest, styled));
+            }
+            Block::Code(lines) => {
+                for line in lines {
+                    out.push_str("    ");
+                    out.push_str(&paint(&line, Style::CODE, styled));
+                    out.push('\n');
+                }
+            }
+            Block::Rule => {
+                out.push_str(&"─".repeat(width.min(40)));
+                out.push('\n');
+            }
+        }
+        out.push('\n');
+    }
+
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+    out
+}
+
+/// Contents of all fenced code blocks, in order. Used to extract example inputs.
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    parse_blocks(markdown)
+        .into_iter()
+        .filter_map(|block| match block {
+            Block::Code(lines) => Some(lines.join("\n") + "\n"),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Proposes the expected example answers of both parts: the last emphasized code span
+/// (`` `*42*` ``) in the text of each part. Part two starts at the "Part Two" heading.
+pub fn example_answers(markdown: &str) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+    let mut part = 0;
+
+    for block in parse_blocks(markdown) {
+        let text = match block {
+            Block::Heading(text) if text.contains("Part Two") => {
+                part = 1;
+                continue;
+            }
+            Block::Paragraph(text) | Block::ListItem { text, .. } => text,
+            _ => continue,
+        };
+
+        let mut rest = text.as_str();
+        while let Some(start) = rest.find("`*") {
+            let Some(len) = rest[start + 2..].find("*`") else {
+                break;
+            };
+            let answer = rest[start + 2..start + 2 + len].trim();
+            if !answer.is_empty() {
+                answers[part] = Some(answer.to_string());
+            }
+            rest = &rest[start + 2 + len + 2..];
+        }
+    }
+
+    answers
+}
+
+/// Terminal width from `$COLUMNS` or `stty`, defaulting to 80 columns.
+pub fn terminal_width() -> usize {
+    if let Some(columns) = env::var("COLUMNS").ok().and_then(|c| c.parse().ok()) {
+        return columns;
+    }
+
+    fs::File::open("/dev/tty")
+        .ok()
+        .and_then(|tty| {
+            Command::new("stty")
+                .arg("size")
+                .stdin(tty)
+                .stderr(Stdio::null())
+                .output()
+                .ok()
+        })
+        .and_then(|output| {
+            String::from_utf8(output.stdout)
+                .ok()?
+                .split_whitespace()
+                .nth(1)?
+                .parse()
+                .ok()
+        })
+        .unwrap_or(80)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Block {
+    Heading(String),
+    Paragraph(String),
+    ListItem { marker: String, text: String },
+    Code(Vec<String>),
+    Rule,
+}
+
+fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut code: Option<Vec<String>> = None;
+
+    fn flush(paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>) {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(paragraph.join(" ")));
+            paragraph.clear();
+        }
+    }
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("```") {
+            match code.take() {
+                Some(lines) => blocks.push(Block::Code(lines)),
+                None => {
+                    flush(&mut paragraph, &mut blocks);
+                    code = Some(vec![]);
+                }
+            }
+            continue;
+        }
+
+        if let Some(lines) = code.as_mut() {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+            continue;
+        }
+
+        if trimmed.len() >= 3
+            && (trimmed.chars().all(|c| c == '-') || trimmed.chars().all(|c| c == '='))
+        {
+            if paragraph.is_empty() {
+                blocks.push(Block::Rule);
+            } else {
+                blocks.push(Block::Heading(paragraph.join(" ")));
+                paragraph.clear();
+            }
+            continue;
+        }
+
+        let hashes = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Heading(trimmed[hashes..].trim().to_string()));
+            continue;
+        }
+
+        if let Some((marker, text)) = list_marker(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::ListItem {
+                marker,
+                text: text.to_string(),
+            });
+            continue;
+        }
+
+        // indented lines continue the previous list item.
+        if paragraph.is_empty() && line.starts_with(' ') {
+            if let Some(Block::ListItem { text, .. }) = blocks.last_mut() {
+                text.push(' ');
+                text.push_str(trimmed);
+                continue;
+            }
+        }
+
+        paragraph.push(trimmed);
+    }
+
+    flush(&mut paragraph, &mut blocks);
+    if let Some(lines) = code {
+        blocks.push(Block::Code(lines));
+    }
+
+    blocks
+}
+
+fn list_marker(line: &str) -> Option<(String, &str)> {
+    for bullet in ["* ", "- ", "+ "] {
+        if let Some(text) = line.strip_prefix(bullet) {
+            return Some(("•".into(), text.trim_start()));
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && line[digits..].starts_with(". ") {
+        return Some((
+            line[..digits + 1].to_string(),
+            line[digits + 2..].trim_start(),
+        ));
+    }
+
+    None
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Style {
+    bold: bool,
+    italic: bool,
+    code: bool,
+}
+
+impl Style {
+    const PLAIN: Style = Style {
+        bold: false,
+        italic: false,
+        code: false,
+    };
+    const BOLD: Style = Style {
+        bold: true,
+        ..Style::PLAIN
+    };
+    const CODE: Style = Style {
+        code: true,
+        ..Style::PLAIN
+    };
+}
+
+/// A word is a run of non-whitespace, made up of differently styled pieces.
+type Word = Vec<(String, Style)>;
+
+fn inline_words(text: &str, base: Style) -> Vec<Word> {
+    let mut words: Vec<Word> = vec![];
+    let mut current: Word = vec![];
+    let mut style = base;
+
+    let push_char = |c: char, style: Style, current: &mut Word, words: &mut Vec<Word>| {
+        if c.is_whitespace() {
+            if !current.is_empty() {
+                words.push(std::mem::take(current));
+            }
+            return;
+        }
+        match current.last_mut() {
+            Some((piece, piece_style)) if *piece_style == style => piece.push(c),
+            _ => current.push((c.to_string(), style)),
+        }
+    };
+
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if !style.code && i + 1 < chars.len() && chars[i + 1].is_ascii_punctuation() => {
+                push_char(chars[i + 1], style, &mut current, &mut words);
+                i += 2;
+                continue;
+            }
+            // emphasis inside code spans is kept, aoc-cli renders `<code><em>` as `*...*`.
+            '`' if style.code || chars[i + 1..].contains(&'`') => {
+                style.code = !style.code;
+                i += 1;
+                continue;
+            }
+            '*' | '_' if c == '*' || !style.code => {
+                let double = chars.get(i + 1) == Some(&c);
+                // `_` only delimits emphasis at word boundaries, e.g. not in `part_one`.
+                let boundary = c == '*'
+                    || i == 0
+                    || !chars[i - 1].is_alphanumeric()
+                    || chars.get(i + 1).is_none_or(|n| !n.is_alphanumeric());
+                if boundary {
+                    if c == '*' || double {
+                        style.bold = !style.bold;
+                    } else {
+                        style.italic = !style.italic;
+                    }
+                    i += if double { 2 } else { 1 };
+                    continue;
+                }
+            }
+            '[' => {
+                if let Some(close) = chars[i + 1..].iter().position(|c| *c == ']') {
+                    let after = i + 1 + close + 1;
+                    if chars.get(after) == Some(&'(') {
+                        if let Some(end) = chars[after..].iter().position(|c| *c == ')') {
+                            let label = chars[i + 1..i + 1 + close].iter().collect::<String>();
+                            for word in inline_words(
+                                &label,
+                                Style {
+                                    italic: true,
+                                    ..style
+                                },
+                            ) {
+                                for (piece, piece_style) in word {
+                                    for c in piece.chars() {
+                                        push_char(c, piece_style, &mut current, &mut words);
+                                    }
+                                }
+                                push_char(' ', style, &mut current, &mut words);
+                            }
+                            // links are glued to what follows, e.g. punctuation.
+                            if let Some(last) = words.pop() {
+                                current = last;
+                            }
+                            i = after + end + 1;
+                            continue;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        push_char(c, style, &mut current, &mut words);
+        i += 1;
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn word_width(word: &Word) -> usize {
+    word.iter().map(|(piece, _)| piece.chars().count()).sum()
+}
+
+fn paint(text: &str, style: Style, styled: bool) -> String {
+    if !styled || style == Style::PLAIN {
+        return text.to_string();
+    }
+
+    let mut out = String::new();
+    if style.bold {
+        out.push_str(ANSI_BOLD);
+    }
+    if style.italic {
+        out.push_str(ANSI_ITALIC);
+    }
+    if style.code {
+        out.push_str(ANSI_CODE);
+    }
+    out.push_str(text);
+    out.push_str(ANSI_RESET);
+    out
+}
+
+fn wrap(words: &[Word], width: usize, first_indent: &str, indent: &str, styled: bool) -> String {
+    let mut out = String::from(first_indent);
+    let mut line_width = first_indent.chars().count();
+    let mut line_start = true;
+
+    for word in words {
+        let w = word_width(word);
+        if !line_start && line_width + 1 + w > width {
+            out.push('\n');
+            out.push_str(indent);
+            line_width = indent.chars().count();
+            line_start = true;
+        }
+        if !line_start {
+            out.push(' ');
+            line_width += 1;
+        }
+        for (piece, style) in word {
+            out.push_str(&paint(piece, *style, styled));
+        }
+        line_width += w;
+        line_start = false;
+    }
+
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = r"\--- Day 1: Trebuchet?! ---
+----------
+
+Something is wrong with global snow production, and you've been selected to take a look.
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the calibration values are `12` and `38`. Adding these produces `*142*`.
+
+* first item
+* second [link](https://example.com).
+";
+
+    #[test]
+    fn test_parse_blocks() {
+        let blocks = parse_blocks(PUZZLE);
+        assert_eq!(
+            blocks[0],
+            Block::Heading(r"\--- Day 1: Trebuchet?! ---".into())
+        );
+        assert_eq!(
+            blocks[3],
+            Block::Code(vec!["1abc2".into(), "pqr3stu8vwx".into()])
+        );
+        assert_eq!(
+            blocks[5],
+            Block::ListItem {
+                marker: "•".into(),
+                text: "first item".into()
+            }
+        );
+        assert_eq!(blocks.len(), 7);
+    }
+
+    #[test]
+    fn test_code_blocks() {
+        assert_eq!(
+            code_blocks(PUZZLE),
+            vec!["1abc2\npqr3stu8vwx\n".to_string()]
+        );
+        assert!(code_blocks("no code here").is_empty());
+    }
+
+    #[test]
+    fn test_example_answers() {
+        assert_eq!(example_answers(PUZZLE), [Some("142".into()), None]);
+
+        let puzzle = format!(
+            "{PUZZLE}\n\\--- Part Two ---\n----------\n\nNow `*two1nine*` gives `*29*`, in total `*281*`.\n\n*What is the sum?*\n"
+        );
+        assert_eq!(
+            example_answers(&puzzle),
+            [Some("142".into()), Some("281".into())]
+        );
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let rendered = render(PUZZLE, 40, false);
+        assert_eq!(
+            rendered,
+            "--- Day 1: Trebuchet?! ---
+
+Something is wrong with global snow
+production, and you've been selected to
+take a look.
+
+For example:
+
+    1abc2
+    pqr3stu8vwx
+
+In this example, the calibration values
+are 12 and 38. Adding these produces
+142.
+
+  • first item
+
+  • second link.
+"
+        );
+    }
+
+    #[test]
+    fn test_render_styled() {
+        let rendered = render("Adding these produces `*142*`, _really_.", 80, true);
+        assert_eq!(
+            rendered,
+            format!(
+                "Adding these produces {ANSI_BOLD}{ANSI_CODE}142{ANSI_RESET}, {ANSI_ITALIC}really{ANSI_RESET}.\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,21 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+pub mod alloc;
+pub mod alternatives;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod commands;
+pub mod differential;
+pub mod input;
+pub mod layout;
+pub mod markdown;
+pub mod readme;
+pub mod record;
+pub mod runner;
+pub mod solution;
+pub mod store;
+pub mod submit;
+pub mod timings;
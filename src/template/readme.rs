@@ -0,0 +1,228 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::Duration;
+
+use super::timings::History;
+
+/// Encloses the generated section of `README.md`. Everything outside of the markers is kept,
+/// including the stars section that the `readme-stars` workflow maintains between its own
+/// `advent_readme_stars` markers.
+pub const MARKER: &str = "<!--- aoc runtimes table --->";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The answer is recorded and did not fail its last check.
+    Solved,
+    /// The last benchmark ran with `--check` and the answer did not match.
+    Failed,
+    Unsolved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Part {
+    pub status: Status,
+    /// Median of the latest benchmark of the part.
+    pub elapsed: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub parts: [Part; 2],
+    /// Latest benchmark of the parse phase, for two-phase days.
+    pub parse: Option<Duration>,
+}
+
+/// Builds the rows of `days` from the latest benchmark of every part in `history`. `recorded`
+/// tells whether an answer is recorded for a day and part.
+pub fn rows(days: &[u8], history: &History, recorded: impl Fn(u8, u8) -> bool) -> Vec<Row> {
+    let latest = |day: u8, part: u8| {
+        history
+            .entries
+            .iter()
+            .rev()
+            .find(|e| e.bench && e.day == day && e.part == part)
+    };
+
+    days.iter()
+        .map(|&day| Row {
+            day,
+            parts: [1, 2].map(|part| {
+                let entry = latest(day, part);
+                let failed = entry
+                    .and_then(|e| e.check.as_deref())
+                    .is_some_and(|check| check == "fail" || check == "error");
+                Part {
+                    status: match (failed, recorded(day, part)) {
+                        (true, _) => Status::Failed,
+                        (false, true) => Status::Solved,
+                        (false, false) => Status::Unsolved,
+                    },
+                    elapsed: entry.map(|e| e.elapsed),
+                }
+            }),
+            parse: latest(day, 0).map(|e| e.elapsed),
+        })
+        .collect()
+}
+
+fn format_part(part: &Part) -> String {
+    let status = match part.status {
+        Status::Solved => "⭐",
+        Status::Failed => "❌",
+        Status::Unsolved => "",
+    };
+    match part.elapsed {
+        Some(elapsed) => format!("{status} `{elapsed:.2?}`").trim().to_string(),
+        None => status.to_string(),
+    }
+}
+
+/// Renders the results table of a year, without the markers.
+pub fn table(year: u16, rows: &[Row]) -> String {
+    let mut table = format!("## {year} Runtimes\n\n");
+    table += "| Day | Part 1 | Part 2 | Parse |\n";
+    table += "| :---: | :---: | :---: | :---: |\n";
+
+    for row in rows {
+        let parse = row
+            .parse
+            .map(|elapsed| format!("`{elapsed:.2?}`"))
+            .unwrap_or_default();
+        table += &format!(
+            "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {} | {} | {parse} |\n",
+            format_part(&row.parts[0]),
+            format_part(&row.parts[1]),
+            day = row.day,
+        );
+    }
+
+    let total: Duration = rows
+        .iter()
+        .flat_map(|row| [row.parts[0].elapsed, row.parts[1].elapsed, row.parse])
+        .flatten()
+        .sum();
+    if !total.is_zero() {
+        table += &format!("\n**Total: {total:.2?}** (latest benchmark of every part)\n");
+    }
+    table
+}
+
+/// Replaces the section between the two markers of `readme` with `section`. Without markers, the
+/// section is appended.
+pub fn replace_section(readme: &str, section: &str) -> String {
+    let start = readme.find(MARKER);
+    let end = start.and_then(|start| {
+        let after = start + MARKER.len();
+        readme[after..].find(MARKER).map(|end| after + end)
+    });
+
+    match (start, end) {
+        (Some(start), Some(end)) => {
+            format!("{}{MARKER}\n{section}{}", &readme[..start], &readme[end..])
+        }
+        _ => {
+            let separator = if readme.is_empty() || readme.ends_with('\n') {
+                ""
+            } else {
+                "\n"
+            };
+            format!("{readme}{separator}\n{MARKER}\n{section}{MARKER}\n")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::timings::Entry;
+
+    fn entry(day: u8, part: u8, micros: u64, bench: bool, check: Option<&str>) -> Entry {
+        Entry {
+            timestamp: 1,
+            commit: "abc".into(),
+            label: None,
+            bench,
+            day,
+            part,
+            elapsed: Duration::from_micros(micros),
+            check: check.map(String::from),
+            build: None,
+        }
+    }
+
+    #[test]
+    fn test_rows() {
+        let history = History {
+            entries: vec![
+                entry(1, 1, 50, true, None),
+                entry(1, 1, 40, true, Some("pass")),
+                entry(1, 2, 10, false, None),
+                entry(2, 0, 5, true, None),
+                entry(2, 1, 7, true, Some("fail")),
+            ],
+        };
+        let rows = rows(&[1, 2], &history, |day, part| day == 1 || part == 1);
+
+        assert_eq!(
+            rows[0].parts,
+            [
+                Part {
+                    status: Status::Solved,
+                    elapsed: Some(Duration::from_micros(40))
+                },
+                Part {
+                    status: Status::Solved,
+                    elapsed: None
+                },
+            ]
+        );
+        assert_eq!(rows[0].parse, None);
+        assert_eq!(rows[1].parts[0].status, Status::Failed);
+        assert_eq!(rows[1].parts[1].status, Status::Unsolved);
+        assert_eq!(rows[1].parse, Some(Duration::from_micros(5)));
+    }
+
+    #[test]
+    fn test_table() {
+        let rows = [Row {
+            day: 3,
+            parts: [
+                Part {
+                    status: Status::Solved,
+                    elapsed: Some(Duration::from_micros(1500)),
+                },
+                Part {
+                    status: Status::Unsolved,
+                    elapsed: None,
+                },
+            ],
+            parse: None,
+        }];
+        assert_eq!(
+            table(2023, &rows),
+            "## 2023 Runtimes\n\n| Day | Part 1 | Part 2 | Parse |\n| :---: | :---: | :---: | :---: |\n| [Day 3](https://adventofcode.com/2023/day/3) | ⭐ `1.50ms` |  |  |\n\n**Total: 1.50ms** (latest benchmark of every part)\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!("# Title\n\n{MARKER}\nold\n{MARKER}\n\nFooter\n");
+        assert_eq!(
+            replace_section(&readme, "new\n"),
+            format!("# Title\n\n{MARKER}\nnew\n{MARKER}\n\nFooter\n")
+        );
+        assert_eq!(
+            replace_section("# Title", "new\n"),
+            format!("# Title\n\n{MARKER}\nnew\n{MARKER}\n")
+        );
+
+        let stars = "<!--- advent_readme_stars table --->\n## 2023 Results\n<!--- advent_readme_stars table --->\n";
+        assert_eq!(
+            replace_section(stars, "new\n"),
+            format!("{stars}\n{MARKER}\nnew\n{MARKER}\n")
+        );
+    }
+}
@@ -0,0 +1,409 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use super::{alloc::AllocStats, answer::Answer, answers::CheckStatus, bench::BenchStats};
+
+/// Machine-readable result of running a single part, emitted as one JSON line per part. The parse
+/// phase of a two-phase day is recorded as part 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// Single-shot time, or the median when benchmarked.
+    pub elapsed: Duration,
+    pub bench: Option<BenchStats>,
+    /// Allocations of a single run, with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+    /// Comparison against the recorded answer, set with `--check`.
+    pub check: Option<CheckStatus>,
+    /// Why the part has no answer, if it did not return on its own.
+    pub failure: Option<Failure>,
+    /// Measured while a timed-out part was still running in the background, so `elapsed` is
+    /// likely inflated.
+    pub unreliable: bool,
+}
+
+/// A part that crashed or exceeded `--timeout`, as opposed to one that returned `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    TimedOut(Duration),
+    Panicked(String),
+}
+
+impl Failure {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Failure::TimedOut(_) => "timeout",
+            Failure::Panicked(_) => "panic",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::TimedOut(limit) => write!(f, "timed out (limit: {limit:.2?})"),
+            Failure::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+impl Record {
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    pub fn is_parse(&self) -> bool {
+        self.part == 0
+    }
+
+    /// Whether `elapsed` is a meaningful runtime: a solved part or a successful parse.
+    pub fn has_timing(&self) -> bool {
+        self.is_solved() || (self.is_parse() && self.failure.is_none())
+    }
+
+    pub fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Some(answer) => format!("\"{}\"", escape_json(&answer.to_string())),
+            None => "null".into(),
+        };
+        let bench = match &self.bench {
+            Some(stats) => format!(
+                ",\"bench\":{{\"samples\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+                stats.samples,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            ),
+            None => String::new(),
+        };
+        let alloc = match &self.alloc {
+            Some(stats) => format!(
+                ",\"alloc\":{{\"allocations\":{},\"bytes\":{},\"peak_bytes\":{}}}",
+                stats.allocations, stats.bytes, stats.peak
+            ),
+            None => String::new(),
+        };
+        let check = match &self.check {
+            Some(CheckStatus::Fail { expected }) => format!(
+                ",\"check\":\"fail\",\"expected\":\"{}\"",
+                escape_json(&expected.to_string())
+            ),
+            Some(status) => format!(",\"check\":\"{}\"", status.as_str()),
+            None => String::new(),
+        };
+        let failure = match &self.failure {
+            Some(failure) => format!(
+                ",\"failure\":\"{}\",\"message\":\"{}\"",
+                failure.as_str(),
+                escape_json(&failure.to_string())
+            ),
+            None => String::new(),
+        };
+        let unreliable = match self.unreliable {
+            true => ",\"unreliable\":true",
+            false => "",
+        };
+        // the parse phase has no answer, only a runtime
+        let (result, solved) = match self.is_parse() {
+            true => ("\"phase\":\"parse\",".to_string(), String::new()),
+            false => (
+                format!("\"answer\":{answer},"),
+                format!(",\"solved\":{}", self.is_solved()),
+            ),
+        };
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},{}\"elapsed_ns\":{}{}{}{}{}{}{}}}",
+            self.year,
+            self.day,
+            self.part,
+            result,
+            self.elapsed.as_nanos(),
+            solved,
+            bench,
+            alloc,
+            check,
+            failure,
+            unreliable
+        )
+    }
+}
+
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Stream that records are written to, selected with `--json <stdout|stderr>`.
+///
+/// When records go to stdout, the human-readable output is suppressed so the stream stays parseable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordStream {
+    Stdout,
+    Stderr,
+}
+
+impl RecordStream {
+    pub fn emit(&self, record: &Record) {
+        let line = record.to_json();
+        // records are best-effort: a closed pipe should not abort the run.
+        let _ = match self {
+            RecordStream::Stdout => writeln!(io::stdout(), "{line}"),
+            RecordStream::Stderr => writeln!(io::stderr(), "{line}"),
+        };
+    }
+}
+
+impl FromStr for RecordStream {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "stdout" => Ok(RecordStream::Stdout),
+            "stderr" => Ok(RecordStream::Stderr),
+            _ => Err(format!(
+                "unknown stream \"{s}\", expected \"stdout\" or \"stderr\""
+            )),
+        }
+    }
+}
+
+impl Display for RecordStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordStream::Stdout => write!(f, "stdout"),
+            RecordStream::Stderr => write!(f, "stderr"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let record = Record {
+            year: 2023,
+            day: 1,
+            part: 2,
+            answer: Some("281".into()),
+            elapsed: Duration::from_nanos(74),
+            alloc: None,
+            bench: None,
+            check: None,
+            failure: None,
+            unreliable: false,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":1,"part":2,"answer":"281","elapsed_ns":74,"solved":true}"#
+        );
+
+        let record = Record {
+            year: 2023,
+            day: 25,
+            part: 2,
+            answer: None,
+            elapsed: Duration::from_micros(3),
+            alloc: None,
+            bench: None,
+            check: None,
+            failure: None,
+            unreliable: false,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":25,"part":2,"answer":null,"elapsed_ns":3000,"solved":false}"#
+        );
+    }
+
+    #[test]
+    fn test_to_json_with_bench() {
+        let record = Record {
+            year: 2023,
+            day: 17,
+            part: 1,
+            answer: Some("102".into()),
+            elapsed: Duration::from_nanos(20),
+            alloc: None,
+            bench: Some(BenchStats {
+                samples: 3,
+                min: Duration::from_nanos(10),
+                median: Duration::from_nanos(20),
+                mean: Duration::from_nanos(25),
+                stddev: Duration::from_nanos(5),
+            }),
+            check: None,
+            failure: None,
+            unreliable: false,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":17,"part":1,"answer":"102","elapsed_ns":20,"solved":true,"bench":{"samples":3,"min_ns":10,"median_ns":20,"mean_ns":25,"stddev_ns":5}}"#
+        );
+    }
+
+    #[test]
+    fn test_to_json_with_check() {
+        let mut record = Record {
+            year: 2023,
+            day: 4,
+            part: 1,
+            answer: Some("13".into()),
+            elapsed: Duration::ZERO,
+            alloc: None,
+            bench: None,
+            check: Some(CheckStatus::Pass),
+            failure: None,
+            unreliable: false,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":4,"part":1,"answer":"13","elapsed_ns":0,"solved":true,"check":"pass"}"#
+        );
+
+        record.check = Some(CheckStatus::Fail {
+            expected: "12".into(),
+        });
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":4,"part":1,"answer":"13","elapsed_ns":0,"solved":true,"check":"fail","expected":"12"}"#
+        );
+    }
+
+    #[test]
+    fn test_to_json_escapes_answer() {
+        let record = Record {
+            year: 2023,
+            day: 10,
+            part: 1,
+            answer: Some("#..\"#\n\\".into()),
+            elapsed: Duration::ZERO,
+            alloc: None,
+            bench: None,
+            check: None,
+            failure: None,
+            unreliable: false,
+        };
+        assert_eq!(
+            record.to_json(),
+            r##"{"year":2023,"day":10,"part":1,"answer":"#..\"#\n\\","elapsed_ns":0,"solved":true}"##
+        );
+    }
+
+    #[test]
+    fn test_to_json_unreliable() {
+        let record = Record {
+            year: 2023,
+            day: 21,
+            part: 1,
+            answer: Some("16".into()),
+            elapsed: Duration::from_nanos(40),
+            alloc: None,
+            bench: None,
+            check: None,
+            failure: None,
+            unreliable: true,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":21,"part":1,"answer":"16","elapsed_ns":40,"solved":true,"unreliable":true}"#
+        );
+    }
+
+    #[test]
+    fn test_to_json_with_failure() {
+        let mut record = Record {
+            year: 2023,
+            day: 23,
+            part: 2,
+            answer: None,
+            elapsed: Duration::from_secs(1),
+            alloc: None,
+            bench: None,
+            check: None,
+            failure: Some(Failure::TimedOut(Duration::from_secs(1))),
+            unreliable: false,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":23,"part":2,"answer":null,"elapsed_ns":1000000000,"solved":false,"failure":"timeout","message":"timed out (limit: 1.00s)"}"#
+        );
+
+        record.failure = Some(Failure::Panicked(
+            "internal error: entered unreachable code".into(),
+        ));
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":23,"part":2,"answer":null,"elapsed_ns":1000000000,"solved":false,"failure":"panic","message":"panicked: internal error: entered unreachable code"}"#
+        );
+    }
+
+    #[test]
+    fn test_to_json_parse_phase() {
+        let record = Record {
+            year: 2023,
+            day: 22,
+            part: 0,
+            answer: None,
+            elapsed: Duration::from_nanos(1500),
+            alloc: None,
+            bench: None,
+            check: None,
+            failure: None,
+            unreliable: false,
+        };
+        assert!(record.has_timing());
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":22,"part":0,"phase":"parse","elapsed_ns":1500}"#
+        );
+    }
+
+    #[test]
+    fn test_to_json_with_alloc() {
+        let record = Record {
+            year: 2023,
+            day: 14,
+            part: 1,
+            answer: Some("136".into()),
+            elapsed: Duration::from_nanos(900),
+            alloc: Some(AllocStats {
+                allocations: 12,
+                bytes: 2048,
+                peak: 1024,
+            }),
+            bench: None,
+            check: None,
+            failure: None,
+            unreliable: false,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":14,"part":1,"answer":"136","elapsed_ns":900,"solved":true,"alloc":{"allocations":12,"bytes":2048,"peak_bytes":1024}}"#
+        );
+    }
+}
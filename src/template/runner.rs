@@ -0,0 +1,989 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    any::Any,
+    cell::Cell,
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::{
+    alloc::{self, AllocStats},
+    alternatives,
+    answer::Answer,
+    answers,
+    bench::BenchStats,
+    bench::{self, BenchConfig},
+    input,
+    record::{Failure, Record, RecordStream},
+    solution::{Day, ParsedInput, Solution},
+    timings::{self, HistoryOptions},
+};
+use crate::{InputError, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Stack size of the threads that run solutions. Spawned threads default to 2 MiB, far less than
+/// the 8 MiB of the main thread, and deeply recursive solutions (e.g. day 21 in a debug build)
+/// would overflow it and abort the whole process.
+const SOLVER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Options of the commands that run solutions: `solve`, `all`, `bench` and `check`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// `--json <stdout|stderr>`: where JSON records are written to.
+    pub records: Option<RecordStream>,
+    /// `--bench`: sample every part instead of timing a single run.
+    pub bench: Option<BenchConfig>,
+    /// `--check`: compare answers against `src/answers/NN.toml`.
+    pub check: bool,
+    pub timeout: Timeouts,
+    /// `--raw-input`: pass inputs to the solutions as they are on disk, without normalizing them.
+    pub raw_input: bool,
+}
+
+impl RunOptions {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let records = args.opt_value_from_str("--json")?;
+        let check = args.contains("--check");
+        let raw_input = args.contains("--raw-input");
+        let timeout = Timeouts(args.values_from_str("--timeout")?);
+        let bench = if args.contains("--bench") {
+            Some(BenchConfig::from_args(args)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            records,
+            bench,
+            check,
+            timeout,
+            raw_input,
+        })
+    }
+
+    /// Reads the input of a day, or `source` (a path, or `-` for stdin) instead. The input is
+    /// [normalized](input::normalize) unless `--raw-input` is given.
+    pub fn load_input(
+        &self,
+        year: u16,
+        day: u8,
+        source: Option<&str>,
+    ) -> Result<String, InputError> {
+        let raw = match source {
+            Some(source) => crate::read_input(source),
+            None => crate::try_read_file_raw("inputs", year, day),
+        }?;
+        Ok(if self.raw_input {
+            raw
+        } else {
+            input::normalize(&raw)
+        })
+    }
+
+    /// The human-readable output is skipped when records are written to stdout.
+    pub fn is_human(&self) -> bool {
+        self.records != Some(RecordStream::Stdout)
+    }
+}
+
+/// `--timeout [<day>[.<part>]=]<ms>`, repeatable: give up on a part after this long. A limit for a
+/// day or for a single part (`.0` being the parse phase) takes precedence over the limit for all
+/// parts, e.g. `--timeout 5000 --timeout 23.2=30000` gives part two of day 23 thirty seconds.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timeouts(pub Vec<Timeout>);
+
+/// A single `--timeout` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout {
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub limit: Duration,
+}
+
+impl FromStr for Timeout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (target, limit) = match s.split_once('=') {
+            Some((target, limit)) => (Some(target), limit),
+            None => (None, s),
+        };
+        let limit = limit
+            .parse()
+            .map(Duration::from_millis)
+            .map_err(|_| format!("invalid time limit \"{limit}\""))?;
+
+        let Some(target) = target else {
+            return Ok(Self {
+                day: None,
+                part: None,
+                limit,
+            });
+        };
+        let (day, part) = match target.split_once('.') {
+            Some((day, part)) => (day, Some(part)),
+            None => (target, None),
+        };
+        let day = day
+            .parse()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| format!("invalid day \"{day}\""))?;
+        let part = part
+            .map(|part| {
+                part.parse()
+                    .ok()
+                    .filter(|part| *part <= 2)
+                    .ok_or_else(|| format!("invalid part \"{part}\""))
+            })
+            .transpose()?;
+        Ok(Self {
+            day: Some(day),
+            part,
+            limit,
+        })
+    }
+}
+
+impl Timeouts {
+    /// The same limit for every part.
+    pub fn all(limit: Duration) -> Self {
+        Self(vec![Timeout {
+            day: None,
+            part: None,
+            limit,
+        }])
+    }
+
+    /// Limit of a part of a day, part 0 being the parse phase. The most specific value applies,
+    /// the last one given if there are several.
+    pub fn get(&self, day: u8, part: u8) -> Option<Duration> {
+        self.0
+            .iter()
+            .filter(|t| t.day.is_none_or(|d| d == day) && t.part.is_none_or(|p| p == part))
+            .max_by_key(|t| (t.day.is_some(), t.part.is_some()))
+            .map(|t| t.limit)
+    }
+}
+
+/// Runs (or benchmarks) a single part, prints its result and returns the matching [`Record`].
+pub fn run_part<T: Into<Answer>>(
+    year: u16,
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> Option<T> + Send + 'static,
+    input: &str,
+    options: &RunOptions,
+) -> Record {
+    if options.is_human() {
+        print_part_header(part);
+    }
+
+    let record = measure_part(year, day, part, func, Arc::from(input), options);
+
+    if options.is_human() {
+        print_result(&record);
+    }
+    if let Some(stream) = options.records {
+        stream.emit(&record);
+    }
+
+    record
+}
+
+/// Runs (or benchmarks) a single part without printing anything.
+fn measure_part<I, T>(
+    year: u16,
+    day: u8,
+    part: u8,
+    func: impl Fn(&I) -> Option<T> + Send + 'static,
+    input: Arc<I>,
+    options: &RunOptions,
+) -> Record
+where
+    I: ?Sized + Send + Sync + 'static,
+    T: Into<Answer>,
+{
+    let outcome = measure(
+        move |input: &I| func(input).map(Into::into),
+        input,
+        options,
+        options.timeout.get(day, part),
+    );
+
+    let (timed, failure) = Timed::split(outcome);
+    let answer = timed.result;
+
+    let check = options
+        .check
+        .then(|| answers::check(year, day, part, answer.as_ref()));
+
+    Record {
+        year,
+        day,
+        part,
+        answer,
+        elapsed: timed.elapsed,
+        bench: timed.bench,
+        alloc: timed.alloc,
+        check,
+        failure,
+        unreliable: timed.unreliable,
+    }
+}
+
+/// Runs (or benchmarks) the parse phase of a two-phase day, recorded as part 0.
+fn measure_parse(
+    year: u16,
+    day: u8,
+    parse: fn(&str) -> ParsedInput,
+    input: Arc<str>,
+    options: &RunOptions,
+) -> (Record, Option<ParsedInput>) {
+    let outcome = measure(
+        move |input: &str| Some(parse(input)),
+        input,
+        options,
+        options.timeout.get(day, 0),
+    );
+    let (timed, failure) = Timed::split(outcome);
+
+    let record = Record {
+        year,
+        day,
+        part: 0,
+        answer: None,
+        elapsed: timed.elapsed,
+        bench: timed.bench,
+        alloc: timed.alloc,
+        check: None,
+        failure,
+        unreliable: timed.unreliable,
+    };
+    (record, timed.result)
+}
+
+/// Result of a phase with its single-shot time (or the median when benchmarked).
+struct Timed<T> {
+    result: Option<T>,
+    elapsed: Duration,
+    bench: Option<BenchStats>,
+    /// Allocations of a single run, see [`alloc::measure`].
+    alloc: Option<AllocStats>,
+    /// Whether an abandoned phase was running in the background, see [`abandoned_phases`].
+    unreliable: bool,
+}
+
+impl<T> Timed<T> {
+    /// Splits the outcome of [`measure`] into what is known about the run and its failure.
+    fn split(outcome: Result<Timed<T>, (Failure, Duration)>) -> (Timed<T>, Option<Failure>) {
+        match outcome {
+            Ok(timed) => (timed, None),
+            Err((failure, elapsed)) => (
+                Timed {
+                    result: None,
+                    elapsed,
+                    bench: None,
+                    alloc: None,
+                    unreliable: false,
+                },
+                Some(failure),
+            ),
+        }
+    }
+}
+
+/// Phases that timed out and whose threads are still running in the background.
+static ABANDONED: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of abandoned phases still running. They compete for the CPU with every phase measured
+/// meanwhile, so those timings are marked as unreliable.
+pub fn abandoned_phases() -> usize {
+    ABANDONED.load(Ordering::SeqCst)
+}
+
+/// Times (or benchmarks) a phase.
+///
+/// A panic is caught and reported as a [`Failure`]. With a timeout the phase runs on its own
+/// thread, which is abandoned (and keeps running in the background) once the timeout expires.
+fn measure<I, T>(
+    func: impl Fn(&I) -> Option<T> + Send + 'static,
+    input: Arc<I>,
+    options: &RunOptions,
+    timeout: Option<Duration>,
+) -> Result<Timed<T>, (Failure, Duration)>
+where
+    I: ?Sized + Send + Sync + 'static,
+    T: Send + 'static,
+{
+    let bench = options.bench;
+    let contended = abandoned_phases() > 0;
+
+    let outcome = match timeout {
+        None => panic::catch_unwind(AssertUnwindSafe(|| time(&func, &input, &bench)))
+            .map_err(panic_failure),
+        Some(timeout) => {
+            let (sender, receiver) = mpsc::channel();
+            let abandoned = Arc::new(Mutex::new(false));
+            let worker_abandoned = abandoned.clone();
+            thread::Builder::new()
+                .stack_size(SOLVER_STACK_SIZE)
+                .spawn(move || {
+                    let outcome =
+                        panic::catch_unwind(AssertUnwindSafe(|| time(&func, &input, &bench)));
+                    // nobody waits for the outcome of an abandoned phase anymore
+                    let abandoned = worker_abandoned.lock().unwrap();
+                    if *abandoned {
+                        ABANDONED.fetch_sub(1, Ordering::SeqCst);
+                    } else {
+                        let _ = sender.send(outcome.map_err(panic_failure));
+                    }
+                })
+                .expect("failed to spawn solver thread");
+
+            match receiver.recv_timeout(timeout) {
+                Ok(outcome) => outcome,
+                Err(RecvTimeoutError::Timeout) => {
+                    let mut abandoned = abandoned.lock().unwrap();
+                    // the phase may have finished while waiting for the lock
+                    match receiver.try_recv() {
+                        Ok(outcome) => outcome,
+                        Err(_) => {
+                            *abandoned = true;
+                            ABANDONED.fetch_add(1, Ordering::SeqCst);
+                            Err((Failure::TimedOut(timeout), timeout))
+                        }
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => Err((
+                    Failure::Panicked("worker thread exited".into()),
+                    Duration::ZERO,
+                )),
+            }
+        }
+    };
+
+    outcome.map(|timed| Timed {
+        unreliable: contended || abandoned_phases() > 0,
+        ..timed
+    })
+}
+
+fn time<I: ?Sized, T>(
+    func: impl Fn(&I) -> Option<T>,
+    input: &I,
+    bench: &Option<BenchConfig>,
+) -> Timed<T> {
+    match bench {
+        Some(config) => {
+            // allocations are counted for the first (unmeasured) run only
+            let first = Cell::new(true);
+            let alloc = Cell::new(None);
+            let counted = |input: &I| {
+                if first.replace(false) {
+                    let (result, stats) = alloc::measure(|| func(input));
+                    alloc.set(stats);
+                    result
+                } else {
+                    func(input)
+                }
+            };
+
+            let (result, stats) = bench::bench(counted, input, config);
+            Timed {
+                result,
+                elapsed: stats.map_or(Default::default(), |s| s.median),
+                bench: stats,
+                alloc: alloc.get(),
+                unreliable: false,
+            }
+        }
+        None => {
+            let timer = Instant::now();
+            let (result, alloc) = alloc::measure(|| func(input));
+            Timed {
+                result,
+                elapsed: timer.elapsed(),
+                bench: None,
+                alloc,
+                unreliable: false,
+            }
+        }
+    }
+}
+
+fn panic_failure(payload: Box<dyn Any + Send>) -> (Failure, Duration) {
+    (Failure::Panicked(panic_message(payload)), Duration::ZERO)
+}
+
+/// The message of a caught panic.
+pub(super) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic payload".into(),
+        },
+    }
+}
+
+fn print_part_header(part: u8) {
+    match part {
+        0 => println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET),
+        part => println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET),
+    }
+}
+
+fn print_result(record: &Record) {
+    let mut timing = match &record.bench {
+        Some(stats) => format!(
+            "min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?}, samples: {}",
+            stats.min, stats.median, stats.mean, stats.stddev, stats.samples
+        ),
+        None => format!("elapsed: {:.2?}", record.elapsed),
+    };
+    if let Some(stats) = &record.alloc {
+        timing.push_str(&format!(
+            ", allocs: {}, allocated: {}, peak: {}",
+            stats.allocations,
+            alloc::format_bytes(stats.bytes),
+            alloc::format_bytes(stats.peak)
+        ));
+    }
+    let timing = format!("({timing})");
+
+    match (&record.answer, &record.failure) {
+        (_, Some(failure)) => println!("{failure}"),
+        (Some(result), None) => println!("{result} {ANSI_ITALIC}{timing}{ANSI_RESET}"),
+        (None, None) if record.is_parse() => println!("{ANSI_ITALIC}{timing}{ANSI_RESET}"),
+        (None, None) => println!("not solved."),
+    }
+
+    if let Some(check) = &record.check {
+        println!("{check}");
+    }
+    if record.unreliable {
+        println!("⚠️ timing unreliable, a timed-out part was still running in the background");
+    }
+}
+
+/// Runs the phases of a day against its input: both parts, or parse and both parts for two-phase
+/// days. With `live`, each phase is printed and emitted as soon as it finishes.
+fn solve_parts(day: &Day, input: &str, options: &RunOptions, live: bool) -> Vec<Record> {
+    let human = live && options.is_human();
+    let phase = |part: u8, measure: &mut dyn FnMut() -> Record| {
+        if human {
+            print_part_header(part);
+        }
+        let record = measure();
+        if human {
+            print_result(&record);
+        }
+        if let (true, Some(stream)) = (live, options.records) {
+            stream.emit(&record);
+        }
+        record
+    };
+
+    let input: Arc<str> = Arc::from(input);
+    let Some(parsed_day) = day.parsed else {
+        return vec![
+            phase(1, &mut || {
+                measure_part(day.year, day.day, 1, day.part_one, input.clone(), options)
+            }),
+            phase(2, &mut || {
+                measure_part(day.year, day.day, 2, day.part_two, input.clone(), options)
+            }),
+        ];
+    };
+
+    let mut parsed = None;
+    let parse = phase(0, &mut || {
+        let (record, input) =
+            measure_parse(day.year, day.day, parsed_day.parse, input.clone(), options);
+        parsed = input;
+        record
+    });
+
+    // parts without parsed input fail with the parse phase, they are not merely unsolved
+    let failure = parse.failure.clone();
+    let mut records = vec![parse];
+    for (part, func) in [(1, parsed_day.part_one), (2, parsed_day.part_two)] {
+        records.push(phase(part, &mut || match &parsed {
+            Some(parsed) => measure_part(day.year, day.day, part, func, parsed.clone(), options),
+            None => Record {
+                failure: failure.clone(),
+                ..unsolved(day, part)
+            },
+        }));
+    }
+    records
+}
+
+fn unsolved(day: &Day, part: u8) -> Record {
+    Record {
+        year: day.year,
+        day: day.day,
+        part,
+        answer: None,
+        elapsed: Default::default(),
+        bench: None,
+        alloc: None,
+        check: None,
+        failure: None,
+        unreliable: false,
+    }
+}
+
+/// Runs both parts of a single day. Used by the `src/bin/NN.rs` wrappers, which take the same
+/// options as `cargo solve`.
+pub fn run<S: Solution>() {
+    let mut args = pico_args::Arguments::from_env();
+    let (options, history, source, inputs_dir) =
+        match RunOptions::from_args(&mut args).and_then(|options| {
+            let history = HistoryOptions::from_args(&mut args)?;
+            let source: Option<String> = args.opt_value_from_str("--input")?;
+            let inputs_dir: Option<PathBuf> = args.opt_value_from_str("--inputs-dir")?;
+            Ok((options, history, source, inputs_dir))
+        }) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {e}");
+                process::exit(1);
+            }
+        };
+
+    match inputs_dir {
+        Some(dir) => run_inputs_dir(&Day::of::<S>(), &options, &dir),
+        None => run_day(&Day::of::<S>(), &options, &history, source),
+    }
+}
+
+/// Runs both parts of a single day against its input. The input is read once, outside of any
+/// measured region.
+///
+/// `source` (a path, or `-` for stdin) replaces the input from `src/inputs`. The recorded answers
+/// belong to the real input, so they are not checked against another one.
+pub fn run_day(day: &Day, options: &RunOptions, history: &HistoryOptions, source: Option<String>) {
+    if source.is_some() && options.check && options.is_human() {
+        println!("Not checking answers, the recorded answers are those of the real input.");
+    }
+    let options = &RunOptions {
+        check: options.check && source.is_none(),
+        ..options.clone()
+    };
+
+    let input = &match options.load_input(day.year, day.day, source.as_deref()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {e}");
+            process::exit(1);
+        }
+    };
+    let records = solve_parts(day, input, options, true);
+
+    // only benchmarks are stable enough to be worth keeping, and only for the real input
+    if options.bench.is_some() && source.is_none() {
+        if let Err(e) = timings::record(day.year, &records, history, options.is_human()) {
+            eprintln!("Failed to update timing history: {e}");
+        }
+    }
+
+    if has_check_failures(&records) {
+        process::exit(1);
+    }
+}
+
+/// Runs both parts of a single day against every input in `dir`, and prints a table of the
+/// results. Answers are checked against the sidecar files of the inputs instead of
+/// `src/answers`, see [`alternatives::discover`].
+pub fn run_inputs_dir(day: &Day, options: &RunOptions, dir: &Path) {
+    let inputs = match alternatives::discover(dir) {
+        Ok(inputs) if inputs.is_empty() => {
+            eprintln!("No inputs found in \"{}\".", dir.display());
+            process::exit(1);
+        }
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Failed to list inputs: {e}");
+            process::exit(1);
+        }
+    };
+    let options = RunOptions {
+        check: false,
+        ..options.clone()
+    };
+
+    let mut rows = vec![];
+    for alternative in inputs {
+        let source = alternative.path.to_string_lossy();
+        let records = match options.load_input(day.year, day.day, Some(&source)) {
+            Ok(input) => solve_parts(day, &input, &options, false),
+            Err(e) => {
+                eprintln!("Failed to read input: {e}");
+                vec![]
+            }
+        };
+        let records: Vec<Record> = records
+            .into_iter()
+            .map(|record| Record {
+                check: alternative.check(record.part, record.answer.as_ref()),
+                ..record
+            })
+            .collect();
+
+        if let Some(stream) = options.records {
+            for record in &records {
+                stream.emit(record);
+            }
+        }
+        rows.push(alternatives::Row {
+            name: alternative.name,
+            records,
+        });
+    }
+
+    if options.is_human() {
+        print!("{}", alternatives::table(&rows));
+    }
+    if rows.iter().any(|row| has_check_failures(&row.records)) {
+        process::exit(1);
+    }
+}
+
+pub fn has_check_failures(records: &[Record]) -> bool {
+    records
+        .iter()
+        .any(|r| r.check.as_ref().is_some_and(|c| c.is_failure()))
+}
+
+/// Runs every registered day in-process on `jobs` worker threads and returns one record per
+/// phase. Results are printed grouped per day and in day order, no matter which day finishes
+/// first.
+pub fn run_all(days: &[Day], options: &RunOptions, jobs: usize) -> Vec<Record> {
+    run_all_with(days, options, jobs, solve_day)
+}
+
+/// [`run_all`] with the function that solves a single day.
+fn run_all_with(
+    days: &[Day],
+    options: &RunOptions,
+    jobs: usize,
+    solve: impl Fn(&Day, &RunOptions) -> Option<Vec<Record>> + Sync,
+) -> Vec<Record> {
+    let jobs = jobs.clamp(1, days.len().max(1));
+    if jobs == 1 {
+        return days
+            .iter()
+            .flat_map(|day| report_day(day, solve(day, options), options))
+            .collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let (next, solve) = (&next, &solve);
+            thread::Builder::new()
+                .stack_size(SOLVER_STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(day) = days.get(i) else {
+                        break;
+                    };
+                    if sender.send((i, solve(day, options))).is_err() {
+                        break;
+                    }
+                })
+                .expect("failed to spawn worker thread");
+        }
+        drop(sender);
+
+        // days can finish out of order, hold them back until all earlier days are printed
+        let mut pending = BTreeMap::new();
+        let mut reported = 0;
+        let mut records = vec![];
+        for (i, solved) in receiver {
+            pending.insert(i, solved);
+            while let Some(solved) = pending.remove(&reported) {
+                records.extend(report_day(&days[reported], solved, options));
+                reported += 1;
+            }
+        }
+        records
+    })
+}
+
+/// Solves a day without printing, `None` if the day has no input.
+fn solve_day(day: &Day, options: &RunOptions) -> Option<Vec<Record>> {
+    let input = match options.load_input(day.year, day.day, None) {
+        Ok(input) => input,
+        Err(e) => {
+            if !e.is_not_found() {
+                eprintln!("Failed to read input: {e}");
+            }
+            return None;
+        }
+    };
+
+    Some(solve_parts(day, &input, options, false))
+}
+
+/// Prints and emits the records of a day. A day without input gets unsolved records.
+fn report_day(day: &Day, solved: Option<Vec<Record>>, options: &RunOptions) -> Vec<Record> {
+    let human = options.is_human();
+
+    if human {
+        println!("----------");
+        println!("{ANSI_BOLD}| Day {:02} |{ANSI_RESET}", day.day);
+        println!("----------");
+    }
+
+    let records = match solved {
+        Some(records) => {
+            if human {
+                for record in &records {
+                    print_part_header(record.part);
+                    print_result(record);
+                }
+            }
+            records
+        }
+        None => {
+            if human {
+                println!("Not solved.");
+            }
+            vec![unsolved(day, 1), unsolved(day, 2)]
+        }
+    };
+
+    if let Some(stream) = options.records {
+        for record in &records {
+            stream.emit(record);
+        }
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::*;
+    use crate::template::solution::ParsedDay;
+
+    fn day(day: u8) -> Day {
+        Day {
+            year: 2023,
+            day,
+            part_one: |_| None,
+            part_two: |_| None,
+            parsed: None,
+        }
+    }
+
+    fn solved(day: &Day) -> Option<Vec<Record>> {
+        Some(vec![Record {
+            answer: Some(Answer::from(day.day)),
+            ..unsolved(day, 1)
+        }])
+    }
+
+    #[test]
+    fn test_run_all_keeps_day_order() {
+        let days: Vec<Day> = (1..=6).map(day).collect();
+        // later days finish first
+        let solve = |day: &Day, _: &RunOptions| {
+            thread::sleep(Duration::from_millis(10 * (7 - day.day as u64)));
+            solved(day)
+        };
+
+        let records = run_all_with(&days, &RunOptions::default(), 3, solve);
+        let order: Vec<u8> = records.iter().map(|record| record.day).collect();
+        assert_eq!(order, [1, 2, 3, 4, 5, 6]);
+        assert!(records.iter().all(Record::is_solved));
+    }
+
+    /// Uses about 4 MiB of stack, more than the default of spawned threads.
+    fn recurse(depth: usize) -> usize {
+        let frame = black_box([0_u8; 1024]);
+        match depth {
+            0 => frame[0] as usize,
+            depth => recurse(depth - 1) + frame[depth % 1024] as usize,
+        }
+    }
+
+    #[test]
+    fn test_run_all_workers_have_large_stacks() {
+        let days: Vec<Day> = (1..=2).map(day).collect();
+        let solve = |day: &Day, _: &RunOptions| {
+            black_box(recurse(4096));
+            solved(day)
+        };
+
+        let records = run_all_with(&days, &RunOptions::default(), 2, solve);
+        assert_eq!(records.len(), 2);
+    }
+
+    fn outcome(record: &Record) -> String {
+        match (&record.failure, &record.answer) {
+            (Some(failure), _) => failure.to_string(),
+            (None, Some(answer)) => answer.to_string(),
+            (None, None) => "not solved".into(),
+        }
+    }
+
+    fn part(func: fn(&str) -> Option<u64>, options: &RunOptions) -> Record {
+        measure_part(2023, 1, 1, func, Arc::from("input"), options)
+    }
+
+    fn parse(parse: fn(&str) -> ParsedInput, options: &RunOptions) -> Record {
+        measure_parse(2023, 1, parse, Arc::from("input"), options).0
+    }
+
+    fn parsed_day(parse: fn(&str) -> ParsedInput) -> Day {
+        Day {
+            parsed: Some(ParsedDay {
+                parse,
+                part_one: |_| Some(Answer::from(1)),
+                part_two: |_| None,
+            }),
+            ..day(1)
+        }
+    }
+
+    #[test]
+    fn test_failures_stay_separate() {
+        let timeout = RunOptions {
+            timeout: Timeouts::all(Duration::from_secs(10)),
+            ..Default::default()
+        };
+        for options in [RunOptions::default(), timeout] {
+            assert_eq!(outcome(&part(|_| Some(7), &options)), "7");
+            assert_eq!(outcome(&part(|_| None, &options)), "not solved");
+            assert_eq!(
+                outcome(&part(|_| panic!("boom"), &options)),
+                "panicked: boom"
+            );
+
+            assert_eq!(outcome(&parse(|_| Arc::new(7), &options)), "not solved");
+            assert_eq!(
+                outcome(&parse(|_| panic!("bad input"), &options)),
+                "panicked: bad input"
+            );
+
+            let records = solve_parts(&parsed_day(|_| Arc::new(7)), "", &options, false);
+            let outcomes: Vec<String> = records.iter().map(outcome).collect();
+            assert_eq!(outcomes, ["not solved", "1", "not solved"]);
+
+            // parts cannot run without parsed input, they fail with the parse phase
+            let records = solve_parts(&parsed_day(|_| panic!("bad input")), "", &options, false);
+            let outcomes: Vec<String> = records.iter().map(outcome).collect();
+            assert_eq!(outcomes, ["panicked: bad input"; 3]);
+        }
+    }
+
+    /// The only test that abandons phases, so the [`abandoned_phases`] count is its own.
+    #[test]
+    fn test_timeouts() {
+        // the limit of day 1 overrides the one of all days
+        let options = RunOptions {
+            timeout: Timeouts(vec!["10000".parse().unwrap(), "1=50".parse().unwrap()]),
+            ..Default::default()
+        };
+        let slow_part = |_: &str| {
+            thread::sleep(Duration::from_secs(1));
+            Some(1)
+        };
+        let slow_parse = |_: &str| -> ParsedInput {
+            thread::sleep(Duration::from_secs(1));
+            Arc::new(1)
+        };
+
+        let record = part(slow_part, &options);
+        assert_eq!(
+            record.failure,
+            Some(Failure::TimedOut(Duration::from_millis(50)))
+        );
+        assert_eq!(outcome(&record), "timed out (limit: 50.00ms)");
+        assert!(!record.unreliable);
+
+        let record = parse(slow_parse, &options);
+        assert_eq!(outcome(&record), "timed out (limit: 50.00ms)");
+        let records = solve_parts(&parsed_day(slow_parse), "", &options, false);
+        let outcomes: Vec<String> = records.iter().map(outcome).collect();
+        assert_eq!(outcomes, ["timed out (limit: 50.00ms)"; 3]);
+        assert_eq!(abandoned_phases(), 3);
+
+        // measured while the abandoned phases compete for the CPU
+        let record = part(|_| Some(2), &options);
+        assert_eq!(outcome(&record), "2");
+        assert!(record.unreliable);
+
+        while abandoned_phases() > 0 {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(!part(|_| Some(2), &options).unreliable);
+        assert!(!part(|_| Some(2), &RunOptions::default()).unreliable);
+    }
+
+    #[test]
+    fn test_timeout_threads_have_large_stacks() {
+        let options = RunOptions {
+            timeout: Timeouts::all(Duration::from_secs(10)),
+            ..Default::default()
+        };
+        let record = part(|_| Some(recurse(4096) as u64), &options);
+        assert_eq!(outcome(&record), "0");
+    }
+
+    #[test]
+    fn test_timeout_limits() {
+        let timeout = |s: &str| s.parse::<Timeout>();
+        assert_eq!(
+            timeout("23.2=30000"),
+            Ok(Timeout {
+                day: Some(23),
+                part: Some(2),
+                limit: Duration::from_secs(30),
+            })
+        );
+        assert!(timeout("5s").is_err());
+        assert!(timeout("26=100").is_err());
+        assert!(timeout("23.3=100").is_err());
+        assert!(timeout("23.=100").is_err());
+
+        let timeouts = Timeouts(
+            ["5000", "23.2=30000", "23=10000", "5.0=1", "5000=1"]
+                .into_iter()
+                .filter_map(|s| timeout(s).ok())
+                .collect(),
+        );
+        let limit = |day, part| timeouts.get(day, part).map(|d| d.as_millis());
+        assert_eq!(limit(1, 1), Some(5000));
+        assert_eq!(limit(23, 2), Some(30000));
+        assert_eq!(limit(23, 1), Some(10000));
+        assert_eq!(limit(23, 0), Some(10000));
+        assert_eq!(limit(5, 0), Some(1));
+        assert_eq!(limit(5, 1), Some(5000));
+        assert_eq!(Timeouts::default().get(1, 1), None);
+        assert_eq!(
+            Timeouts(vec![timeout("100").unwrap(), timeout("200").unwrap()]).get(1, 1),
+            Some(Duration::from_millis(200))
+        );
+    }
+}
@@ -0,0 +1,227 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{any::Any, sync::Arc};
+
+use super::answer::Answer;
+
+/// A solved (or partially solved) puzzle day.
+///
+/// Implementations are usually generated by the [`solution!`](crate::solution) macro, which
+/// delegates to the `part_one` and `part_two` functions of a day module.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    fn part_one(input: &str) -> Option<Self::PartOne>;
+    fn part_two(input: &str) -> Option<Self::PartTwo>;
+
+    /// Set for two-phase solutions, see [`Parsed`].
+    const PARSED: Option<ParsedDay> = None;
+}
+
+/// A solution that parses its input once and solves both parts from the parsed input, so that
+/// the runner can time parsing separately. [`Solution::part_one`] and [`Solution::part_two`]
+/// still accept the raw input and parse it themselves.
+pub trait Parsed: Solution {
+    type Input: Send + Sync + 'static;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part_one(input: &Self::Input) -> Option<Self::PartOne>;
+    fn part_two(input: &Self::Input) -> Option<Self::PartTwo>;
+}
+
+/// Parsed input of a [`Parsed`] solution with its type erased.
+pub type ParsedInput = Arc<dyn Any + Send + Sync>;
+
+/// Type-erased phases of a [`Parsed`] solution.
+#[derive(Clone, Copy)]
+pub struct ParsedDay {
+    pub parse: fn(&str) -> ParsedInput,
+    pub part_one: fn(&(dyn Any + Send + Sync)) -> Option<Answer>,
+    pub part_two: fn(&(dyn Any + Send + Sync)) -> Option<Answer>,
+}
+
+impl ParsedDay {
+    pub const fn of<S: Parsed>() -> Self {
+        Self {
+            parse: parse::<S>,
+            part_one: parsed_part_one::<S>,
+            part_two: parsed_part_two::<S>,
+        }
+    }
+}
+
+fn parse<S: Parsed>(input: &str) -> ParsedInput {
+    Arc::new(S::parse(input))
+}
+
+fn parsed_part_one<S: Parsed>(input: &(dyn Any + Send + Sync)) -> Option<Answer> {
+    let input = input.downcast_ref().expect("parsed input of another day");
+    <S as Parsed>::part_one(input).map(Into::into)
+}
+
+fn parsed_part_two<S: Parsed>(input: &(dyn Any + Send + Sync)) -> Option<Answer> {
+    let input = input.downcast_ref().expect("parsed input of another day");
+    <S as Parsed>::part_two(input).map(Into::into)
+}
+
+/// Type-erased entry of the day registry, see [`crate::days::ALL`].
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: fn(&str) -> Option<Answer>,
+    pub part_two: fn(&str) -> Option<Answer>,
+    pub parsed: Option<ParsedDay>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            part_one: part_one::<S>,
+            part_two: part_two::<S>,
+            parsed: S::PARSED,
+        }
+    }
+}
+
+fn part_one<S: Solution>(input: &str) -> Option<Answer> {
+    S::part_one(input).map(Into::into)
+}
+
+fn part_two<S: Solution>(input: &str) -> Option<Answer> {
+    S::part_two(input).map(Into::into)
+}
+
+/// Implements [`Solution`] for the `part_one` and `part_two` functions of the calling module.
+/// The year is taken from the `YEAR` constant of the parent (year) module.
+///
+/// Usage: `solution!(DAY, AnswerType)` or `solution!(DAY, PartOneType, PartTwoType)`.
+///
+/// Two-phase solutions add `parse: InputType`, e.g. `solution!(DAY, AnswerType, parse: InputType)`.
+/// The module then provides `parse(&str) -> InputType` and takes `&InputType` in both parts, see
+/// [`Parsed`]. `InputType` has to be `pub`.
+#[macro_export]
+macro_rules! solution {
+    ($day:expr, $answer:ty, parse: $input:ty) => {
+        $crate::solution!($day, $answer, $answer, parse: $input);
+    };
+    ($day:expr, $part_one:ty, $part_two:ty, parse: $input:ty) => {
+        pub struct Solver;
+
+        impl $crate::template::solution::Solution for Solver {
+            const YEAR: u16 = super::YEAR;
+            const DAY: u8 = $day;
+
+            type PartOne = $part_one;
+            type PartTwo = $part_two;
+
+            fn part_one(input: &str) -> Option<$part_one> {
+                part_one(&parse(input))
+            }
+
+            fn part_two(input: &str) -> Option<$part_two> {
+                part_two(&parse(input))
+            }
+
+            const PARSED: Option<$crate::template::solution::ParsedDay> =
+                Some($crate::template::solution::ParsedDay::of::<Self>());
+        }
+
+        impl $crate::template::solution::Parsed for Solver {
+            type Input = $input;
+
+            fn parse(input: &str) -> $input {
+                parse(input)
+            }
+
+            fn part_one(input: &$input) -> Option<$part_one> {
+                part_one(input)
+            }
+
+            fn part_two(input: &$input) -> Option<$part_two> {
+                part_two(input)
+            }
+        }
+    };
+    ($day:expr, $answer:ty) => {
+        $crate::solution!($day, $answer, $answer);
+    };
+    ($day:expr, $part_one:ty, $part_two:ty) => {
+        pub struct Solver;
+
+        impl $crate::template::solution::Solution for Solver {
+            const YEAR: u16 = super::YEAR;
+            const DAY: u8 = $day;
+
+            type PartOne = $part_one;
+            type PartTwo = $part_two;
+
+            fn part_one(input: &str) -> Option<$part_one> {
+                part_one(input)
+            }
+
+            fn part_two(input: &str) -> Option<$part_two> {
+                part_two(input)
+            }
+        }
+    };
+}
+
+/// Reads an example of the day: `1` is `src/examples/NN.txt`, any other number `n` the alternate
+/// file `NN_0n.txt`.
+pub fn read_example<S: Solution>(example: u8) -> String {
+    match example {
+        1 => crate::read_file("examples", S::YEAR, S::DAY),
+        n => crate::read_file_alternate("examples", S::YEAR, S::DAY, n),
+    }
+}
+
+/// Generates one `#[test]` per row, each running a solver against an example file of the day
+/// and comparing its result as an [`Answer`]. Extra parameters of the solver follow the input in
+/// parentheses. Expected answers are `None`, or `Some` of an integer literal or a string.
+/// Must be used inside the `tests` module of a day. Two-phase days test the raw-input entry points
+/// through `Solver::part_one` and `Solver::part_two`.
+///
+/// ```ignore
+/// crate::example_tests! {
+///     test_part_one: 1, part_one() => Some(2);
+///     test_part_two: 2, part_two() => Some(6);
+///     test_part_two_age_10: 1, calc_with_galaxy_age(10) => Some(1030);
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    (@test $name:ident, $example:expr, $($solver:ident)::+($($param:expr),*), $expected:expr) => {
+        #[test]
+        fn $name() {
+            #[allow(unused_imports)]
+            use $crate::template::solution::Solution as _;
+
+            let input = $crate::template::solution::read_example::<super::Solver>($example);
+            let answer = $($solver)::+(&input $(, $param)*).map($crate::template::answer::Answer::from);
+            assert_eq!(answer, $expected);
+        }
+    };
+    () => {};
+    ($name:ident: $example:expr, $($solver:ident)::+($($param:expr),* $(,)?) => None; $($rest:tt)*) => {
+        $crate::example_tests!(@test $name, $example, $($solver)::+($($param),*), None);
+        $crate::example_tests!($($rest)*);
+    };
+    ($name:ident: $example:expr, $($solver:ident)::+($($param:expr),* $(,)?) => $expected:expr; $($rest:tt)*) => {
+        $crate::example_tests!(
+            @test $name,
+            $example,
+            $($solver)::+($($param),*),
+            $crate::template::answer::expected($expected)
+        );
+        $crate::example_tests!($($rest)*);
+    };
+}
@@ -0,0 +1,269 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env, fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    ChaCha20Poly1305, Nonce,
+};
+
+use crate::InputError;
+
+/// Hex encoded key, takes precedence over the key file.
+pub const KEY_ENV: &str = "AOC_INPUT_KEY";
+/// Path of the key file, `.aoc-key` in the crate root if not set.
+pub const KEY_FILE_ENV: &str = "AOC_INPUT_KEY_FILE";
+const DEFAULT_KEY_FILE: &str = ".aoc-key";
+
+/// Data folders that are kept in the store.
+pub const FOLDERS: [&str; 2] = ["inputs", "answers"];
+
+const MAGIC: &[u8] = b"AOC1";
+const NONCE_LEN: usize = 12;
+
+/// Key of the encrypted store. Inputs must not be published, so `src/inputs` is not committed;
+/// the encrypted copies in `src/encrypted` can be, and everyone with the key can restore them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        if s.len() != 64 {
+            return Err(format!("expected 64 hex digits, got {}", s.len()));
+        }
+        let mut key = [0; 32];
+        for (byte, pair) in key.iter_mut().zip(s.as_bytes().chunks(2)) {
+            let pair = std::str::from_utf8(pair).map_err(|_| "key is not valid hex")?;
+            *byte = u8::from_str_radix(pair, 16).map_err(|_| "key is not valid hex")?;
+        }
+        Ok(Self(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Reads the key from [`KEY_ENV`] or the key file. `Ok(None)` if no key is configured.
+    pub fn load() -> Result<Option<Self>, String> {
+        if let Ok(hex) = env::var(KEY_ENV) {
+            return Self::from_hex(&hex)
+                .map(Some)
+                .map_err(|e| format!("{KEY_ENV}: {e}"));
+        }
+
+        let path = key_file();
+        match fs::read_to_string(&path) {
+            Ok(hex) => Self::from_hex(&hex)
+                .map(Some)
+                .map_err(|e| format!("{}: {e}", path.display())),
+            Err(e)
+                if e.kind() == io::ErrorKind::NotFound && env::var_os(KEY_FILE_ENV).is_none() =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// Writes a new key file, readable by the owner only. Never overwrites an existing key.
+    pub fn write_new(path: &Path) -> Result<Self, String> {
+        let key = Self::generate();
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        options
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", key.to_hex()))
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(key)
+    }
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+pub fn key_file() -> PathBuf {
+    env::var_os(KEY_FILE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_KEY_FILE))
+}
+
+/// Directory of the encrypted copies of a data folder, e.g. `src/encrypted/inputs` or
+/// `src/encrypted/2022/inputs`.
+pub fn dir(folder: &str, year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(crate::data_dir("encrypted", year))
+        .join(folder)
+}
+
+/// Path of the plain data file that a stored copy belongs to.
+pub fn plain_path(folder: &str, year: u16, file_name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(crate::data_dir(folder, year))
+        .join(file_name)
+}
+
+pub fn path(folder: &str, year: u16, file_name: &str) -> PathBuf {
+    dir(folder, year).join(format!("{file_name}.enc"))
+}
+
+/// Name bound to the ciphertext, so an encrypted file cannot be moved to another day or year.
+fn associated_data(folder: &str, year: u16, file_name: &str) -> String {
+    format!("{year}/{folder}/{file_name}")
+}
+
+/// Encrypts a file as `AOC1 || nonce || ciphertext`.
+pub fn encrypt(key: &Key, name: &str, plaintext: &[u8]) -> Vec<u8> {
+    let cipher = ChaCha20Poly1305::new(&key.0.into());
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext,
+                aad: name.as_bytes(),
+            },
+        )
+        .expect("encryption does not fail for inputs of this size");
+
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, name: &str, data: &[u8]) -> Result<Vec<u8>, String> {
+    let data = data
+        .strip_prefix(MAGIC)
+        .filter(|data| data.len() >= NONCE_LEN)
+        .ok_or("not an encrypted data file")?;
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    ChaCha20Poly1305::new(&key.0.into())
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: name.as_bytes(),
+            },
+        )
+        .map_err(|_| "decryption failed, wrong key or corrupted file".into())
+}
+
+/// Decrypts the stored copy of a data file. `None` if there is no stored copy; without a key the
+/// error is still "not found", as the plain file is.
+pub fn read(folder: &str, year: u16, file_name: &str) -> Option<Result<String, InputError>> {
+    let path = path(folder, year, file_name);
+    let data = match fs::read(&path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return None,
+        Err(source) => return Some(Err(InputError { path, source })),
+    };
+
+    let error = |kind, message: String| {
+        Err(InputError {
+            path: path.clone(),
+            source: io::Error::new(kind, message),
+        })
+    };
+    let key = match Key::load() {
+        Ok(Some(key)) => key,
+        Ok(None) => {
+            return Some(error(
+                io::ErrorKind::NotFound,
+                format!("no key to decrypt it, set {KEY_ENV} or create {DEFAULT_KEY_FILE}"),
+            ))
+        }
+        Err(e) => return Some(error(io::ErrorKind::InvalidData, e)),
+    };
+
+    Some(
+        decrypt(&key, &associated_data(folder, year, file_name), &data)
+            .and_then(|plaintext| {
+                String::from_utf8(plaintext).map_err(|_| "decrypted file is not UTF-8".into())
+            })
+            .or_else(|e| error(io::ErrorKind::InvalidData, e)),
+    )
+}
+
+/// Stores an encrypted copy of `contents`. Returns `false` without touching the file if the
+/// stored copy already has the same contents, so exports do not produce new ciphertexts (and
+/// diffs) for unchanged files.
+pub fn write(
+    key: &Key,
+    folder: &str,
+    year: u16,
+    file_name: &str,
+    contents: &str,
+) -> Result<bool, String> {
+    let path = path(folder, year, file_name);
+    let name = associated_data(folder, year, file_name);
+
+    let unchanged = fs::read(&path)
+        .ok()
+        .and_then(|data| decrypt(key, &name, &data).ok())
+        .is_some_and(|stored| stored == contents.as_bytes());
+    if unchanged {
+        return Ok(false);
+    }
+
+    fs::create_dir_all(dir(folder, year)).map_err(|e| e.to_string())?;
+    fs::write(&path, encrypt(key, &name, contents.as_bytes()))
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let key = Key::generate();
+        let data = encrypt(&key, "2023/inputs/01.txt", b"1abc2\n");
+        assert!(data.starts_with(MAGIC));
+        assert_eq!(
+            decrypt(&key, "2023/inputs/01.txt", &data).unwrap(),
+            b"1abc2\n"
+        );
+        assert_ne!(data, encrypt(&key, "2023/inputs/01.txt", b"1abc2\n"));
+    }
+
+    #[test]
+    fn test_decrypt_rejects() {
+        let key = Key::generate();
+        let data = encrypt(&key, "2023/inputs/01.txt", b"1abc2\n");
+
+        assert!(decrypt(&Key::generate(), "2023/inputs/01.txt", &data).is_err());
+        assert!(decrypt(&key, "2023/inputs/02.txt", &data).is_err());
+        assert!(decrypt(&key, "2023/inputs/01.txt", &data[..data.len() - 1]).is_err());
+        assert!(decrypt(&key, "2023/inputs/01.txt", b"AOC1").is_err());
+        assert!(decrypt(&key, "2023/inputs/01.txt", b"1abc2\n").is_err());
+    }
+
+    #[test]
+    fn test_key_hex() {
+        let key = Key::generate();
+        assert_eq!(Key::from_hex(&key.to_hex()).unwrap(), key);
+        assert_eq!(Key::from_hex(&format!("{}\n", key.to_hex())).unwrap(), key);
+        assert_eq!(Key::from_hex(&"ab".repeat(32)).unwrap().0, [0xab; 32]);
+
+        assert!(Key::from_hex("abcd").is_err());
+        assert!(Key::from_hex(&"zz".repeat(32)).is_err());
+        assert!(Key::from_hex(&"é".repeat(32)).is_err());
+        assert_eq!(format!("{key:?}"), "Key(..)");
+    }
+}
@@ -0,0 +1,439 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::{
+    answer::Answer,
+    answers::{escape, unescape},
+};
+use crate::aoc_cli::{self, AocCliError};
+
+/// Classified response of the puzzle server to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too recently, the answer was not checked.
+    Cooldown,
+    /// The part is locked or already solved.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Verdict::Correct
+        } else if response.contains("too high") {
+            Verdict::TooHigh
+        } else if response.contains("too low") {
+            Verdict::TooLow
+        } else if response.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if response.contains("You gave an answer too recently") {
+            Verdict::Cooldown
+        } else if response.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the server has rejected this exact answer.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::Cooldown => "cooldown",
+            Verdict::WrongLevel => "wrong-level",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+            Verdict::Cooldown,
+            Verdict::WrongLevel,
+            Verdict::Unknown,
+        ]
+        .into_iter()
+        .find(|v| v.as_str() == s)
+        .ok_or_else(|| format!("unknown verdict \"{s}\""))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Exclusive bounds for a numeric answer, derived from "too high" and "too low" responses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bounds {
+    pub above: Option<i128>,
+    pub below: Option<i128>,
+}
+
+impl Display for Bounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.above, self.below) {
+            (Some(above), Some(below)) => write!(f, "{above} < answer < {below}"),
+            (Some(above), None) => write!(f, "answer > {above}"),
+            (None, Some(below)) => write!(f, "answer < {below}"),
+            (None, None) => write!(f, "no bounds known"),
+        }
+    }
+}
+
+/// Per-day history of submitted guesses, stored as tab-separated lines in
+/// `src/submissions/[<year>/]NN.tsv`: `timestamp  part  verdict  answer`. Answers are escaped like
+/// in the answers files, so tabs and newlines stay within their field.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    pub guesses: Vec<Guess>,
+}
+
+impl History {
+    /// Resolved against the crate root, so known-wrong answers are rejected from any directory.
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(crate::data_dir("submissions", year))
+            .join(format!("{day:02}.tsv"))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(path, self.to_tsv()).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let guesses = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let mut fields = line.splitn(4, '\t');
+                let mut next = || {
+                    fields
+                        .next()
+                        .ok_or_else(|| format!("line {}: expected 4 fields", i + 1))
+                };
+                let timestamp = next()?;
+                let part = next()?;
+                let verdict = next()?;
+                let answer = next()?;
+
+                Ok(Guess {
+                    timestamp: timestamp
+                        .parse()
+                        .map_err(|_| format!("line {}: invalid timestamp", i + 1))?,
+                    part: part
+                        .parse()
+                        .map_err(|_| format!("line {}: invalid part", i + 1))?,
+                    verdict: verdict
+                        .parse()
+                        .map_err(|e| format!("line {}: {e}", i + 1))?,
+                    // histories written before answers were escaped may hold stray backslashes
+                    answer: unescape(answer).unwrap_or_else(|| answer.to_string()),
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self { guesses })
+    }
+
+    pub fn to_tsv(&self) -> String {
+        self.guesses
+            .iter()
+            .map(|g| {
+                format!(
+                    "{}\t{}\t{}\t{}\n",
+                    g.timestamp,
+                    g.part,
+                    g.verdict,
+                    escape(&g.answer)
+                )
+            })
+            .collect()
+    }
+
+    pub fn for_part(&self, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses.iter().filter(move |g| g.part == part)
+    }
+
+    pub fn bounds(&self, part: u8) -> Bounds {
+        let numeric = |verdict: Verdict| {
+            self.for_part(part)
+                .filter(move |g| g.verdict == verdict)
+                .filter_map(|g| Answer::from(&g.answer).as_i128())
+        };
+
+        Bounds {
+            above: numeric(Verdict::TooLow).max(),
+            below: numeric(Verdict::TooHigh).min(),
+        }
+    }
+
+    /// Returns why `answer` should not be submitted, if it is known to be wrong.
+    pub fn rejection(&self, part: u8, answer: &str) -> Option<String> {
+        if let Some(correct) = self.for_part(part).find(|g| g.verdict == Verdict::Correct) {
+            return Some(format!(
+                "part {part} was already solved with {}.",
+                correct.answer
+            ));
+        }
+
+        let value = Answer::from(answer);
+        if let Some(previous) = self
+            .for_part(part)
+            .find(|g| Answer::from(&g.answer) == value && g.verdict.is_wrong())
+        {
+            return Some(format!(
+                "{answer} was already submitted and was {}.",
+                previous.verdict
+            ));
+        }
+
+        let bounds = self.bounds(part);
+        if let Some(value) = value.as_i128() {
+            if bounds.above.is_some_and(|above| value <= above)
+                || bounds.below.is_some_and(|below| value >= below)
+            {
+                return Some(format!("{answer} is out of bounds: {bounds}."));
+            }
+        }
+
+        None
+    }
+}
+
+pub enum SubmitError {
+    /// The answer is known to be wrong and was not submitted.
+    Rejected(String),
+    Cli(AocCliError),
+    History(String),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Rejected(reason) => write!(f, "not submitting: {reason}"),
+            SubmitError::Cli(e) => write!(f, "{e}"),
+            SubmitError::History(e) => write!(f, "could not update submission history: {e}"),
+        }
+    }
+}
+
+/// Submits an answer through the aoc-cli `command` unless the history shows it to be wrong, then
+/// records the response in the history at `history_path`.
+pub fn submit(
+    command: &str,
+    day: u8,
+    year: Option<u16>,
+    part: u8,
+    answer: &str,
+    history_path: &Path,
+) -> Result<Verdict, SubmitError> {
+    let mut history = History::load(history_path).map_err(SubmitError::History)?;
+
+    if let Some(reason) = history.rejection(part, answer) {
+        return Err(SubmitError::Rejected(reason));
+    }
+
+    let output = aoc_cli::submit(command, day, year, part, answer).map_err(SubmitError::Cli)?;
+    let response = String::from_utf8_lossy(&output.stdout);
+    print!("{response}");
+
+    let verdict = Verdict::from_response(&response);
+    if verdict == Verdict::Unknown && !output.status.success() {
+        return Err(SubmitError::Cli(AocCliError::BadExitStatus(output)));
+    }
+
+    history.guesses.push(Guess {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        part,
+        answer: answer.to_string(),
+        verdict,
+    });
+    history.save(history_path).map_err(SubmitError::History)?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(part: u8, answer: &str, verdict: Verdict) -> Guess {
+        Guess {
+            timestamp: 1701406800,
+            part,
+            answer: answer.into(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_verdict_from_response() {
+        assert_eq!(
+            Verdict::from_response("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            ),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer. If you're stuck, ..."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently; You have 38s left to wait."),
+            Verdict::Cooldown
+        );
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let history = History {
+            guesses: vec![
+                guess(1, "12", Verdict::TooLow),
+                guess(1, "abc def", Verdict::Wrong),
+                guess(2, "#..#\n#\t.\\\"", Verdict::Wrong),
+            ],
+        };
+        let tsv = history.to_tsv();
+        assert_eq!(tsv.lines().count(), 3);
+        assert!(tsv.ends_with("\t2\twrong\t#..#\\n#\\t.\\\\\\\"\n"));
+        assert_eq!(History::parse(&tsv), Ok(history));
+
+        let legacy = History::parse("1701406800\t1\twrong\ta\\x\"c\n").unwrap();
+        assert_eq!(legacy.guesses[0].answer, "a\\x\"c");
+    }
+
+    #[test]
+    fn test_bounds_and_rejection() {
+        let history = History {
+            guesses: vec![
+                guess(1, "100", Verdict::TooLow),
+                guess(1, "150", Verdict::TooLow),
+                guess(1, "300", Verdict::TooHigh),
+                guess(1, "200", Verdict::Cooldown),
+                guess(2, "7", Verdict::Correct),
+            ],
+        };
+
+        assert_eq!(
+            history.bounds(1),
+            Bounds {
+                above: Some(150),
+                below: Some(300)
+            }
+        );
+        assert!(history.rejection(1, "150").is_some());
+        assert!(history.rejection(1, "120").is_some());
+        assert!(history.rejection(1, "301").is_some());
+        assert_eq!(history.rejection(1, "200"), None);
+        assert_eq!(history.rejection(1, "250"), None);
+        assert!(history.rejection(2, "8").is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_submit_with_fake_aoc() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("aoc-submit-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // fake aoc-cli that answers "too high" and logs its arguments.
+        let script = dir.join("aoc");
+        let log = dir.join("calls.log");
+        fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\necho \"$@\" >> {}\necho \"That's not the right answer; your answer is too high.\"\n",
+                log.display()
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let aoc = script.to_str().unwrap();
+        let history_path = dir.join("01.tsv");
+        let verdict = submit(aoc, 1, Some(2023), 1, "500", &history_path);
+        assert!(matches!(verdict, Ok(Verdict::TooHigh)));
+
+        let history = History::load(&history_path).unwrap();
+        assert_eq!(history.guesses.len(), 1);
+        assert_eq!(history.guesses[0].answer, "500");
+
+        // known-wrong and out-of-bounds answers never reach aoc-cli.
+        assert!(matches!(
+            submit(aoc, 1, Some(2023), 1, "500", &history_path),
+            Err(SubmitError::Rejected(_))
+        ));
+        assert!(matches!(
+            submit(aoc, 1, Some(2023), 1, "600", &history_path),
+            Err(SubmitError::Rejected(_))
+        ));
+
+        let calls = fs::read_to_string(&log).unwrap();
+        assert_eq!(calls.lines().count(), 1);
+        assert!(calls.contains("--day 1 submit 1 500"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
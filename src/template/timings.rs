@@ -0,0 +1,560 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use super::record::Record;
+
+/// Runtime of a single part in one run, see [`History`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Start of the run in milliseconds since the unix epoch. Together with `commit` it identifies
+    /// the run.
+    pub timestamp: u64,
+    pub commit: String,
+    /// Optional name of the run, given with `--label`, to compare against with `--baseline`.
+    pub label: Option<String>,
+    pub bench: bool,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed: Duration,
+    /// Result of `--check` for this part (`pass`, `fail`, `missing` or `error`), if it was checked.
+    pub check: Option<String>,
+    /// `None` for entries written before the build was recorded.
+    pub build: Option<Build>,
+}
+
+impl Entry {
+    fn same_run(&self, other: &Entry) -> bool {
+        self.timestamp == other.timestamp && self.commit == other.commit
+    }
+
+    /// Whether runtimes of both entries are comparable. Entries of an unknown build match any
+    /// build, so older histories can still be compared against.
+    fn same_build(&self, other: &Entry) -> bool {
+        match (&self.build, &other.build) {
+            (Some(build), Some(other)) => build == other,
+            _ => true,
+        }
+    }
+}
+
+/// Build that measured an entry. Runtimes of a debug build or with `alloc-stats` (which counts
+/// every allocation) are not comparable to those of a plain release build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Build {
+    /// `debug` or `release`.
+    pub profile: String,
+    /// Enabled cargo features, in the order of `Cargo.toml`.
+    pub features: Vec<String>,
+}
+
+impl Build {
+    pub fn current() -> Self {
+        let features = [("alloc-stats", cfg!(feature = "alloc-stats"))];
+        Self {
+            profile: if cfg!(debug_assertions) {
+                "debug"
+            } else {
+                "release"
+            }
+            .into(),
+            features: features
+                .into_iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(name, _)| name.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// The entries of one run, in file order.
+pub type Run<'a> = Vec<&'a Entry>;
+
+/// Per-year history of part runtimes, stored as tab-separated lines in
+/// `src/timings/[<year>/]history.tsv`:
+/// `timestamp  commit  label  mode  day  part  elapsed_ns  check  profile  features`.
+/// Older histories end after `elapsed_ns` or `check`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    /// Resolved against the crate root, so runs from any directory share one history.
+    pub fn path(year: u16) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(crate::data_dir("timings", year))
+            .join("history.tsv")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// Appends entries to the file at `path` without rewriting earlier runs.
+    pub fn append(path: &Path, entries: &[Entry]) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut f| f.write_all(to_tsv(entries).as_bytes()))
+            .map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let entries = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let fields: Vec<&str> = line.split('\t').collect();
+                let (fields, check, build) = match fields[..] {
+                    [ref fields @ .., check, profile, features] if fields.len() == 7 => {
+                        (fields, Some(check), Some((profile, features)))
+                    }
+                    [ref fields @ .., check] if fields.len() == 7 => (fields, Some(check), None),
+                    ref fields => (fields, None, None),
+                };
+                let [timestamp, commit, label, mode, day, part, elapsed] = fields[..] else {
+                    return Err(format!("line {}: expected 7, 8 or 10 fields", i + 1));
+                };
+                let invalid = |field: &str| format!("line {}: invalid {field}", i + 1);
+
+                Ok(Entry {
+                    timestamp: timestamp.parse().map_err(|_| invalid("timestamp"))?,
+                    commit: commit.to_string(),
+                    label: (label != "-").then(|| label.to_string()),
+                    bench: match mode {
+                        "bench" => true,
+                        "single" => false,
+                        _ => return Err(invalid("mode")),
+                    },
+                    day: day.parse().map_err(|_| invalid("day"))?,
+                    part: part.parse().map_err(|_| invalid("part"))?,
+                    elapsed: Duration::from_nanos(
+                        elapsed.parse().map_err(|_| invalid("elapsed time"))?,
+                    ),
+                    check: check.filter(|c| *c != "-").map(String::from),
+                    build: match build {
+                        Some(("", _)) => return Err(invalid("profile")),
+                        Some(("-", _)) | None => None,
+                        Some((profile, features)) => Some(Build {
+                            profile: profile.to_string(),
+                            features: features
+                                .split(',')
+                                .filter(|f| *f != "-")
+                                .map(String::from)
+                                .collect(),
+                        }),
+                    },
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self { entries })
+    }
+
+    /// All runs, oldest first.
+    pub fn runs(&self) -> Vec<Run<'_>> {
+        let mut runs: Vec<Run> = vec![];
+        for entry in &self.entries {
+            match runs.iter_mut().find(|run| run[0].same_run(entry)) {
+                Some(run) => run.push(entry),
+                None => runs.push(vec![entry]),
+            }
+        }
+        runs
+    }
+
+    /// The run to compare `current` against: the latest run of the same build whose label or
+    /// commit starts with `name`, or without a name the latest earlier run of the same build and
+    /// mode (single or bench).
+    pub fn baseline(&self, current: &[Entry], name: Option<&str>) -> Option<Run<'_>> {
+        let first = current.first()?;
+        self.runs()
+            .into_iter()
+            .filter(|run| !run[0].same_run(first) && run[0].same_build(first))
+            .rfind(|run| match name {
+                Some(name) => {
+                    run[0].label.as_deref() == Some(name) || run[0].commit.starts_with(name)
+                }
+                None => run[0].bench == first.bench && run[0].timestamp <= first.timestamp,
+            })
+    }
+}
+
+fn to_tsv(entries: &[Entry]) -> String {
+    entries
+        .iter()
+        .map(|e| {
+            format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                e.timestamp,
+                e.commit,
+                e.label.as_deref().unwrap_or("-"),
+                if e.bench { "bench" } else { "single" },
+                e.day,
+                e.part,
+                e.elapsed.as_nanos(),
+                e.check.as_deref().unwrap_or("-"),
+                e.build.as_ref().map_or("-", |b| b.profile.as_str()),
+                match &e.build {
+                    Some(build) if !build.features.is_empty() => build.features.join(","),
+                    _ => "-".into(),
+                }
+            )
+        })
+        .collect()
+}
+
+/// Turns the solved parts (and successful parse phases) of a run into history entries.
+pub fn entries(records: &[Record], commit: &str, label: Option<&str>) -> Vec<Entry> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64);
+    let build = Build::current();
+
+    records
+        .iter()
+        .filter(|r| r.has_timing() && !r.unreliable)
+        .map(|r| Entry {
+            timestamp,
+            commit: commit.to_string(),
+            label: label.map(String::from),
+            bench: r.bench.is_some(),
+            day: r.day,
+            part: r.part,
+            elapsed: r.elapsed,
+            check: r.check.as_ref().map(|c| c.as_str().to_string()),
+            build: Some(build.clone()),
+        })
+        .collect()
+}
+
+/// Short hash of `HEAD`, suffixed with `-dirty` when there are uncommitted changes.
+pub fn git_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{commit}-dirty")
+        }
+        Some(commit) => commit,
+        None => "unknown".into(),
+    }
+}
+
+/// Runtime of a part in the baseline and the current run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Comparison {
+    /// Relative change in percent, positive when the part got slower.
+    pub fn change(&self) -> f64 {
+        let before = self.before.as_secs_f64();
+        if before == 0.0 {
+            return 0.0;
+        }
+        (self.after.as_secs_f64() - before) / before * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Pairs every part of `current` with the same part in `baseline`.
+pub fn compare(baseline: &[&Entry], current: &[Entry]) -> Vec<Comparison> {
+    current
+        .iter()
+        .filter_map(|after| {
+            let before = baseline
+                .iter()
+                .find(|e| e.day == after.day && e.part == after.part)?;
+            Some(Comparison {
+                day: after.day,
+                part: after.part,
+                before: before.elapsed,
+                after: after.elapsed,
+            })
+        })
+        .collect()
+}
+
+/// Options for recording and comparing runtimes, shared by `cargo all` and `cargo solve --bench`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryOptions {
+    /// `--no-history`: do not append this run to the history.
+    pub skip: bool,
+    /// `--label <name>`: name this run so it can be used as a baseline later.
+    pub label: Option<String>,
+    /// `--compare`: report regressions against the previous run (or `--baseline <name>`).
+    pub compare: bool,
+    pub baseline: Option<String>,
+    /// `--threshold <percent>`: slowdown above which a part counts as regressed, 10% by default.
+    pub threshold: f64,
+}
+
+impl HistoryOptions {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let baseline: Option<String> = args.opt_value_from_str("--baseline")?;
+        Ok(Self {
+            skip: args.contains("--no-history"),
+            label: args.opt_value_from_str("--label")?,
+            compare: args.contains("--compare") || baseline.is_some(),
+            baseline,
+            threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
+        })
+    }
+}
+
+/// Appends the solved records of a run to the history of `year` and prints the comparison report
+/// if one was requested and the output is meant for humans.
+pub fn record(
+    year: u16,
+    records: &[Record],
+    options: &HistoryOptions,
+    human: bool,
+) -> Result<(), String> {
+    let path = History::path(year);
+    let history = History::load(&path)?;
+    let current = entries(records, &git_commit(), options.label.as_deref());
+
+    if options.compare && human {
+        report(&history, &current, options);
+    }
+
+    if options.skip || current.is_empty() {
+        return Ok(());
+    }
+    History::append(&path, &current)
+}
+
+fn report(history: &History, current: &[Entry], options: &HistoryOptions) {
+    let Some(baseline) = history.baseline(current, options.baseline.as_deref()) else {
+        match &options.baseline {
+            Some(name) => println!(
+                "No baseline run named \"{name}\" of the same build in the timing history."
+            ),
+            None => {
+                println!("No previous run of the same build in the timing history to compare with.")
+            }
+        }
+        return;
+    };
+
+    let name = match &baseline[0].label {
+        Some(label) => format!("{label} ({})", baseline[0].commit),
+        None => baseline[0].commit.clone(),
+    };
+    println!("Compared with {name}:");
+
+    let comparisons = compare(&baseline, current);
+    let regressions: Vec<_> = comparisons
+        .iter()
+        .filter(|c| c.is_regression(options.threshold))
+        .collect();
+    for c in &regressions {
+        println!(
+            "⚠️  Day {:02} part {}: {:.2?} -> {:.2?} ({:+.1}%)",
+            c.day,
+            c.part,
+            c.before,
+            c.after,
+            c.change()
+        );
+    }
+    println!(
+        "{} of {} parts regressed by more than {}%",
+        regressions.len(),
+        comparisons.len(),
+        options.threshold
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: u64, commit: &str, label: Option<&str>, day: u8, ms: u64) -> Entry {
+        Entry {
+            timestamp,
+            commit: commit.into(),
+            label: label.map(String::from),
+            bench: false,
+            day,
+            part: 1,
+            elapsed: Duration::from_millis(ms),
+            check: None,
+            build: Some(release()),
+        }
+    }
+
+    fn release() -> Build {
+        Build {
+            profile: "release".into(),
+            features: vec![],
+        }
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let entries = vec![
+            entry(1701400000000, "38c5f61", None, 1, 2),
+            Entry {
+                bench: true,
+                part: 2,
+                check: Some("pass".into()),
+                ..entry(1701400000000, "38c5f61-dirty", Some("before-dp"), 12, 150)
+            },
+        ];
+        let tsv = to_tsv(&entries);
+        assert_eq!(
+            tsv,
+            "1701400000000\t38c5f61\t-\tsingle\t1\t1\t2000000\t-\trelease\t-\n1701400000000\t38c5f61-dirty\tbefore-dp\tbench\t12\t2\t150000000\tpass\trelease\t-\n"
+        );
+        assert_eq!(History::parse(&tsv), Ok(History { entries }));
+
+        let features = Build {
+            profile: "debug".into(),
+            features: vec!["alloc-stats".into(), "other".into()],
+        };
+        let entries = vec![Entry {
+            build: Some(features),
+            ..entry(1, "abc", None, 1, 1)
+        }];
+        let tsv = to_tsv(&entries);
+        assert!(tsv.ends_with("\t-\tdebug\talloc-stats,other\n"));
+        assert_eq!(History::parse(&tsv), Ok(History { entries }));
+
+        // histories written before the check and build columns were added
+        let legacy = History::parse("1701400000000\t38c5f61\t-\tsingle\t1\t1\t2000000\n").unwrap();
+        assert_eq!(legacy.entries[0].check, None);
+        assert_eq!(legacy.entries[0].build, None);
+        let legacy =
+            History::parse("1701400000000\t38c5f61\t-\tsingle\t1\t1\t2000000\tpass\n").unwrap();
+        assert_eq!(legacy.entries[0].check.as_deref(), Some("pass"));
+        assert_eq!(legacy.entries[0].build, None);
+        assert!(History::parse("1\tabc\t-\tsingle\t1\t1\t5\t-\t\t-").is_err());
+        assert!(History::parse("1\tabc\t-\tsingle\t1\t1\t5\t-\trelease").is_err());
+        assert!(History::parse("1\tabc\t-\tslow\t1\t1\t5").is_err());
+    }
+
+    #[test]
+    fn test_path() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(History::path(2023), root.join("src/timings/history.tsv"));
+        assert_eq!(
+            History::path(2022),
+            root.join("src/timings/2022/history.tsv")
+        );
+    }
+
+    #[test]
+    fn test_baseline() {
+        let history = History {
+            entries: vec![
+                entry(1, "aaa", Some("v1"), 1, 10),
+                entry(1, "aaa", Some("v1"), 2, 10),
+                entry(2, "bbb", None, 1, 20),
+                entry(3, "ccc", None, 1, 30),
+            ],
+        };
+        let current = [entry(3, "ccc", None, 1, 30)];
+
+        let previous = history.baseline(&current, None).unwrap();
+        assert_eq!(previous[0].commit, "bbb");
+
+        let named = history.baseline(&current, Some("v1")).unwrap();
+        assert_eq!(named.len(), 2);
+        assert_eq!(history.baseline(&current, Some("bb")).unwrap()[0].day, 1);
+        assert!(history.baseline(&current, Some("zzz")).is_none());
+    }
+
+    #[test]
+    fn test_baseline_same_build() {
+        let debug = Build {
+            profile: "debug".into(),
+            features: vec![],
+        };
+        let alloc_stats = Build {
+            features: vec!["alloc-stats".into()],
+            ..release()
+        };
+        let history = History {
+            entries: vec![
+                Entry {
+                    build: None,
+                    ..entry(1, "aaa", Some("v1"), 1, 10)
+                },
+                entry(2, "bbb", Some("v1"), 1, 20),
+                Entry {
+                    build: Some(debug.clone()),
+                    ..entry(3, "ccc", Some("v1"), 1, 300)
+                },
+                Entry {
+                    build: Some(alloc_stats),
+                    ..entry(4, "ddd", None, 1, 40)
+                },
+            ],
+        };
+
+        let current = [entry(5, "eee", None, 1, 20)];
+        assert_eq!(history.baseline(&current, None).unwrap()[0].commit, "bbb");
+        assert_eq!(
+            history.baseline(&current, Some("v1")).unwrap()[0].commit,
+            "bbb"
+        );
+
+        let current = [Entry {
+            build: Some(debug),
+            ..entry(5, "eee", None, 1, 300)
+        }];
+        assert_eq!(history.baseline(&current, None).unwrap()[0].commit, "ccc");
+
+        // runs recorded before the build was, match any build
+        let history = History {
+            entries: history.entries[..1].to_vec(),
+        };
+        assert_eq!(history.baseline(&current, None).unwrap()[0].commit, "aaa");
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = [entry(1, "aaa", None, 1, 10), entry(1, "aaa", None, 2, 10)];
+        let current = [entry(2, "bbb", None, 1, 12), entry(2, "bbb", None, 3, 1)];
+        let comparisons = compare(&baseline.iter().collect::<Vec<_>>(), &current);
+
+        assert_eq!(comparisons.len(), 1);
+        assert!((comparisons[0].change() - 20.0).abs() < 1e-9);
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[0].is_regression(25.0));
+    }
+}
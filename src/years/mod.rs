@@ -0,0 +1,20 @@
+use crate::{days, template::solution::Day};
+
+/// The year used when no `--year` is given. Its days live in `src/days` and its data files directly
+/// in `src/inputs`, `src/examples`, ...
+pub const DEFAULT_YEAR: u16 = 2023;
+
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+/// All years, in order. `cargo scaffold --year` adds new years here.
+#[rustfmt::skip]
+pub const ALL: &[Year] = &[
+    Year { year: 2023, days: days::ALL },
+];
+
+pub fn get(year: u16) -> Option<&'static Year> {
+    ALL.iter().find(|y| y.year == year)
+}
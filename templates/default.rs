@@ -0,0 +1,19 @@
+crate::solution!({{day}}, {{answer_type}});
+
+pub fn part_one(_input: &str) -> Option<{{answer_type}}> {
+    None
+}
+
+pub fn part_two(_input: &str) -> Option<{{answer_type}}> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        test_part_one: 1, part_one() => None;
+        test_part_two: 1, part_two() => None;
+    }
+}
@@ -0,0 +1,78 @@
+crate::solution!({{day}}, {{answer_type}});
+
+use crate::algebra_helpers::PointGrid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Wall,
+    Empty,
+}
+
+impl From<char> for Cell {
+    fn from(value: char) -> Self {
+        match value {
+            '#' => Self::Wall,
+            '.' => Self::Empty,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub type Grid = PointGrid<isize, 2, Cell>;
+
+mod parser {
+    use nom::{
+        character::complete::{line_ending, one_of},
+        combinator::into,
+        error::Error as NomError,
+        multi::{many1, separated_list1},
+        Finish, IResult,
+    };
+
+    use super::{Cell, Grid};
+    use crate::algebra_helpers::Point2;
+
+    pub fn parse(s: &str) -> Result<Grid, NomError<&str>> {
+        let (_, rows) = separated_list1(line_ending, parse_row)(s).finish()?;
+
+        let mut grid = Grid::default();
+        for (y, row) in rows.into_iter().enumerate() {
+            for (x, cell) in row.into_iter().enumerate() {
+                grid.insert(Point2::new(x as isize, y as isize), cell);
+            }
+        }
+        Ok(grid)
+    }
+
+    fn parse_row(s: &str) -> IResult<&str, Vec<Cell>> {
+        many1(parse_cell)(s)
+    }
+
+    fn parse_cell(s: &str) -> IResult<&str, Cell> {
+        into(parse_cell_raw)(s)
+    }
+
+    fn parse_cell_raw(s: &str) -> IResult<&str, char> {
+        one_of("#.")(s)
+    }
+}
+
+pub fn part_one(input: &str) -> Option<{{answer_type}}> {
+    let _grid = parser::parse(input.trim()).ok()?;
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{answer_type}}> {
+    let _grid = parser::parse(input.trim()).ok()?;
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        test_part_one: 1, part_one() => None;
+        test_part_two: 1, part_two() => None;
+    }
+}
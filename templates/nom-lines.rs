@@ -0,0 +1,39 @@
+crate::solution!({{day}}, {{answer_type}});
+
+mod parser {
+    use nom::{
+        character::complete::{i64 as number, line_ending, space1},
+        error::Error as NomError,
+        multi::separated_list1,
+        Finish, IResult,
+    };
+
+    pub fn parse(s: &str) -> Result<Vec<Vec<i64>>, NomError<&str>> {
+        let (_, lines) = separated_list1(line_ending, parse_line)(s).finish()?;
+        Ok(lines)
+    }
+
+    fn parse_line(s: &str) -> IResult<&str, Vec<i64>> {
+        separated_list1(space1, number)(s)
+    }
+}
+
+pub fn part_one(input: &str) -> Option<{{answer_type}}> {
+    let _lines = parser::parse(input.trim()).ok()?;
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{answer_type}}> {
+    let _lines = parser::parse(input.trim()).ok()?;
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        test_part_one: 1, part_one() => None;
+        test_part_two: 1, part_two() => None;
+    }
+}